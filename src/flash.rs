@@ -50,7 +50,6 @@ impl Message {
 pub struct Flash {
     link: ComponentLink<Self>,
     props: Props,
-    timeout: TimeoutService,
     hide_task: Option<TimeoutTask>,
 }
 
@@ -72,7 +71,6 @@ impl Component for Flash {
         Self {
             link,
             props,
-            timeout: TimeoutService::default(),
             hide_task: None,
         }
     }
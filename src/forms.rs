@@ -1,16 +1,57 @@
 use std::borrow::Cow;
 
+pub mod autocomplete;
 pub mod button;
+pub mod changeset;
+pub mod checkbox;
+pub mod date_time;
 pub mod field;
+pub mod field_array;
+pub mod file;
+pub mod form;
+pub mod history;
 pub mod label;
+pub mod password_input;
+#[cfg(feature = "persistence")]
+pub mod persistence;
 pub mod radio;
+pub mod select;
+pub mod slider;
+pub mod stepper;
 pub mod storage;
+pub mod switch;
+pub mod tags_input;
+pub mod text_area;
 pub mod text_input;
+pub mod wizard;
 
 pub mod prelude {
+    #[cfg(feature = "persistence")]
+    pub use super::persistence::{PersistenceOptions, PersistentStorage, StorageArea};
     pub use super::{
-        button::Button, field::Field, label::Label, radio::Radio, storage::FormStorage,
-        text_input::TextInput, FormField,
+        autocomplete::{Autocomplete, Suggestion, SuggestionSource, Suggestions},
+        button::Button,
+        changeset::Changeset,
+        checkbox::Checkbox,
+        date_time::{DateInput, DateTimeInput, TimeInput},
+        field::Field,
+        field_array::{FieldArray, FieldArrayInput},
+        file::{FileHandle, FileInput},
+        form::{Form, SubmissionState},
+        history::{FormHistory, HistoryStorage},
+        label::Label,
+        password_input::{BasicPasswordStrength, PasswordInput, PasswordStrength},
+        radio::Radio,
+        select::{MultiSelect, Select},
+        slider::Slider,
+        stepper::Stepper,
+        storage::FormStorage,
+        switch::Switch,
+        tags_input::TagsInput,
+        text_area::TextArea,
+        text_input::{InputType, TextInput},
+        wizard::{Wizard, WizardStep},
+        FormField,
     };
     pub use crate::title::Title;
 }
@@ -18,3 +59,23 @@ pub mod prelude {
 pub trait FormField: Copy + std::hash::Hash + Eq + PartialEq + std::fmt::Debug + 'static {
     fn form_id(&self) -> Cow<'static, str>;
 }
+
+#[cfg(test)]
+pub(crate) mod testing {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, Hash, Eq, PartialEq)]
+    pub enum Fields {
+        Name,
+        Email,
+        Company,
+        Age,
+        Tags,
+    }
+
+    impl FormField for Fields {
+        fn form_id(&self) -> Cow<'static, str> {
+            Cow::Owned(format!("{:?}", self).to_lowercase())
+        }
+    }
+}
@@ -0,0 +1,337 @@
+use std::{collections::HashMap, rc::Rc, time::Duration};
+
+use yew::prelude::*;
+use yew::services::timeout::{TimeoutService, TimeoutTask};
+
+use crate::forms::FormField;
+
+use super::{storage::FormStorage, text_input::TextInput};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Suggestion<V> {
+    pub label: String,
+    pub value: V,
+}
+
+pub enum Suggestions<V> {
+    /// The suggestions were computed synchronously.
+    Ready(Vec<Suggestion<V>>),
+    /// The suggestions will be delivered through the callback passed to `SuggestionSource::suggest`.
+    Pending,
+}
+
+pub trait SuggestionSource<V> {
+    fn suggest(&self, query: &str, respond: Callback<Vec<Suggestion<V>>>) -> Suggestions<V>;
+}
+
+/// Finds `query` in `label`, ignoring case, returning the byte range of the match within `label`.
+pub fn find_match(label: &str, query: &str) -> Option<(usize, usize)> {
+    let needle = query
+        .chars()
+        .flat_map(char::to_lowercase)
+        .collect::<Vec<_>>();
+    if needle.is_empty() {
+        return None;
+    }
+    // Characters are lowercased one at a time, as lowercasing can change their length, such as for 'İ'
+    label.char_indices().find_map(|(start, _)| {
+        let mut lowercased = label[start..].char_indices().flat_map(|(offset, c)| {
+            let end = start + offset + c.len_utf8();
+            c.to_lowercase().map(move |lowercase| (end, lowercase))
+        });
+        let mut end = start;
+        for expected in needle.iter() {
+            let (char_end, lowercase) = lowercased.next()?;
+            if lowercase != *expected {
+                return None;
+            }
+            end = char_end;
+        }
+        match lowercased.next() {
+            // The match ends partway through the lowercase form of a character
+            Some((next_end, _)) if next_end == end => None,
+            _ => Some((start, end)),
+        }
+    })
+}
+
+pub struct Autocomplete<T, V>
+where
+    T: FormField,
+    V: Clone + std::fmt::Debug + PartialEq + 'static,
+{
+    props: Props<T, V>,
+    query: FormStorage<Option<String>>,
+    suggestions: Vec<Suggestion<V>>,
+    highlighted: Option<usize>,
+    open: bool,
+    loading: bool,
+    generation: usize,
+    debounce_task: Option<TimeoutTask>,
+    link: ComponentLink<Self>,
+}
+
+#[derive(Clone, Properties)]
+pub struct Props<T, V>
+where
+    T: FormField,
+    V: Clone + std::fmt::Debug + PartialEq + 'static,
+{
+    #[prop_or_default]
+    pub on_value_changed: Callback<Option<V>>,
+    pub storage: FormStorage<Option<V>>,
+    pub field: T,
+    pub errors: Option<Rc<HashMap<T, Vec<Rc<Html>>>>>,
+    pub source: Rc<dyn SuggestionSource<V>>,
+    /// The text shown for the value initially in `storage`.
+    #[prop_or_default]
+    pub initial_label: String,
+    #[prop_or_default]
+    pub placeholder: String,
+    #[prop_or_default]
+    pub disabled: bool,
+    #[prop_or(Duration::from_millis(250))]
+    pub debounce: Duration,
+    #[prop_or(1)]
+    pub min_query_length: usize,
+}
+
+pub enum Message<V> {
+    QueryChanged(Option<String>),
+    Search,
+    SuggestionsReceived(usize, Vec<Suggestion<V>>),
+    KeyDown(KeyboardEvent),
+    Choose(usize),
+    Blurred,
+}
+
+impl<T, V> Component for Autocomplete<T, V>
+where
+    T: FormField,
+    V: Clone + std::fmt::Debug + PartialEq + 'static,
+{
+    type Message = Message<V>;
+    type Properties = Props<T, V>;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let query = if props.initial_label.is_empty() {
+            FormStorage::default()
+        } else {
+            FormStorage::new(Some(props.initial_label.clone()))
+        };
+        Self {
+            props,
+            query,
+            link,
+            suggestions: Vec::new(),
+            highlighted: None,
+            open: false,
+            loading: false,
+            generation: 0,
+            debounce_task: None,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Message::QueryChanged(_) => {
+                // Typing invalidates the previous choice
+                if self.props.storage.unchecked_value().is_some() {
+                    self.props.storage.update(None);
+                    self.props.on_value_changed.emit(None);
+                }
+                self.debounce_task = Some(TimeoutService::spawn(
+                    self.props.debounce,
+                    self.link.callback(|_| Message::Search),
+                ));
+                false
+            }
+            Message::Search => {
+                self.debounce_task = None;
+                self.generation += 1;
+                let query = self.query.unchecked_value().unwrap_or_default();
+                if query.chars().count() < self.props.min_query_length {
+                    self.show(Vec::new());
+                    return true;
+                }
+
+                let generation = self.generation;
+                let respond = self.link.callback(move |suggestions| {
+                    Message::SuggestionsReceived(generation, suggestions)
+                });
+                match self.props.source.suggest(&query, respond) {
+                    Suggestions::Ready(suggestions) => self.show(suggestions),
+                    Suggestions::Pending => self.loading = true,
+                }
+                true
+            }
+            Message::SuggestionsReceived(generation, suggestions) => {
+                // Discard responses to queries that have since been replaced
+                if generation != self.generation {
+                    return false;
+                }
+                self.show(suggestions);
+                true
+            }
+            Message::KeyDown(event) => {
+                if !self.open || self.suggestions.is_empty() {
+                    return false;
+                }
+                let count = self.suggestions.len();
+                match event.key().as_str() {
+                    "ArrowDown" => {
+                        event.prevent_default();
+                        self.highlighted = Some(self.highlighted.map_or(0, |i| (i + 1) % count));
+                        true
+                    }
+                    "ArrowUp" => {
+                        event.prevent_default();
+                        self.highlighted = Some(
+                            self.highlighted
+                                .map_or(count - 1, |i| (i + count - 1) % count),
+                        );
+                        true
+                    }
+                    "Enter" => match self.highlighted {
+                        Some(index) => {
+                            event.prevent_default();
+                            self.choose(index);
+                            true
+                        }
+                        None => false,
+                    },
+                    "Escape" => {
+                        self.open = false;
+                        true
+                    }
+                    _ => false,
+                }
+            }
+            Message::Choose(index) => {
+                self.choose(index);
+                true
+            }
+            Message::Blurred => {
+                self.props.storage.touch();
+                false
+            }
+        }
+    }
+
+    fn view(&self) -> Html {
+        let css_class = if self.open && !self.suggestions.is_empty() {
+            "dropdown is-active"
+        } else {
+            "dropdown"
+        };
+        let control_class = if self.loading {
+            "control is-loading"
+        } else {
+            "control"
+        };
+        html! {
+            <div class=css_class onkeydown=self.link.callback(Message::KeyDown)>
+                <div class="dropdown-trigger">
+                    <div class=control_class>
+                        <TextInput<T, String>
+                            field=self.props.field
+                            storage=self.query.clone()
+                            errors=self.props.errors.clone()
+                            on_value_changed=self.link.callback(Message::QueryChanged)
+                            on_blur=self.link.callback(|_| Message::Blurred)
+                            placeholder=&self.props.placeholder
+                            autocomplete=Some(String::from("off"))
+                            disabled=self.props.disabled />
+                    </div>
+                </div>
+                <div class="dropdown-menu" role="listbox">
+                    <div class="dropdown-content">
+                        { self.suggestions.iter().enumerate().map(|(index, suggestion)| self.render_suggestion(index, suggestion)).collect::<Html>() }
+                    </div>
+                </div>
+            </div>
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        self.props = props;
+        true
+    }
+}
+
+impl<T, V> Autocomplete<T, V>
+where
+    T: FormField,
+    V: Clone + std::fmt::Debug + PartialEq + 'static,
+{
+    fn show(&mut self, suggestions: Vec<Suggestion<V>>) {
+        self.loading = false;
+        self.open = !suggestions.is_empty();
+        self.highlighted = None;
+        self.suggestions = suggestions;
+    }
+
+    fn choose(&mut self, index: usize) {
+        if let Some(suggestion) = self.suggestions.get(index) {
+            // Invalidate any search still in flight
+            self.generation += 1;
+            self.debounce_task = None;
+            self.query.update(Some(suggestion.label.clone()));
+            self.props.storage.update(Some(suggestion.value.clone()));
+            self.props
+                .on_value_changed
+                .emit(Some(suggestion.value.clone()));
+        }
+        self.open = false;
+        self.loading = false;
+    }
+
+    fn render_suggestion(&self, index: usize, suggestion: &Suggestion<V>) -> Html {
+        let css_class = if self.highlighted == Some(index) {
+            "dropdown-item is-active"
+        } else {
+            "dropdown-item"
+        };
+        let query = self.query.unchecked_value().unwrap_or_default();
+        let label = match find_match(&suggestion.label, &query) {
+            Some((start, end)) => html! {
+                <>
+                    { &suggestion.label[..start] }
+                    <strong>{ &suggestion.label[start..end] }</strong>
+                    { &suggestion.label[end..] }
+                </>
+            },
+            None => html! { &suggestion.label },
+        };
+        html! {
+            <a
+                class=css_class
+                role="option"
+                onmousedown=self.link.callback(move |e: MouseEvent| {e.prevent_default(); Message::Choose(index)})>
+                { label }
+            </a>
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matching() {
+        assert_eq!(find_match("Portland", "land"), Some((4, 8)));
+        assert_eq!(find_match("Portland", "PORT"), Some((0, 4)));
+        assert_eq!(find_match("Portland", "x"), None);
+        assert_eq!(find_match("Portland", ""), None);
+        assert_eq!(find_match("Zürich", "rich"), Some((3, 7)));
+        assert_eq!(find_match("ZÜRICH", "zür"), Some((0, 4)));
+        assert_eq!(find_match("İstanbul", "stan"), Some((2, 6)));
+        assert_eq!(find_match("İstanbul", "İst"), Some((0, 4)));
+        assert_eq!(
+            find_match("İx", "i"),
+            None,
+            "'İ' lowercases to two characters"
+        );
+    }
+}
@@ -0,0 +1,206 @@
+use std::any::Any;
+#[cfg(feature = "serde")]
+use std::collections::HashMap;
+
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use super::{storage::FormStorage, FormField};
+
+/// The fields of a form whose values differ from their original values, such as for sending only
+/// the changed fields in a PATCH request.
+///
+/// A changeset is a snapshot: later changes to the storages are not reflected.
+pub struct Changeset<F>
+where
+    F: FormField,
+{
+    changes: Vec<(F, FieldChange)>,
+}
+
+struct FieldChange {
+    original: Box<dyn Any>,
+    current: Box<dyn Any>,
+    /// The current value as JSON, if the field was added with `with_serialized_field`.
+    #[cfg(feature = "serde")]
+    json: Option<serde_json::Result<serde_json::Value>>,
+}
+
+/// The original and current value of a changed field.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Change<'a, T> {
+    pub original: &'a T,
+    pub current: &'a T,
+}
+
+impl<F> Default for Changeset<F>
+where
+    F: FormField,
+{
+    fn default() -> Self {
+        Self {
+            changes: Vec::new(),
+        }
+    }
+}
+
+impl<F> std::fmt::Debug for Changeset<F>
+where
+    F: FormField,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Changeset")
+            .field("changed_fields", &self.changed_fields())
+            .finish()
+    }
+}
+
+impl<F> Changeset<F>
+where
+    F: FormField,
+{
+    /// Includes `field` if the value of `storage` differs from its original value.
+    pub fn with_field<T>(mut self, field: F, storage: &FormStorage<T>) -> Self
+    where
+        T: std::fmt::Debug + Default + Clone + PartialEq + 'static,
+    {
+        let original = storage.original_value();
+        let current = storage.unchecked_value();
+        if original != current {
+            self.changes.retain(|(existing, _)| existing != &field);
+            self.changes.push((
+                field,
+                FieldChange {
+                    original: Box::new(original),
+                    current: Box::new(current),
+                    #[cfg(feature = "serde")]
+                    json: None,
+                },
+            ));
+        }
+        self
+    }
+
+    /// Includes `field` like `with_field`, also serializing its current value for `to_json`.
+    #[cfg(feature = "serde")]
+    pub fn with_serialized_field<T>(mut self, field: F, storage: &FormStorage<T>) -> Self
+    where
+        T: std::fmt::Debug + Default + Clone + PartialEq + Serialize + 'static,
+    {
+        self = self.with_field(field, storage);
+        if let Some((_, change)) = self
+            .changes
+            .iter_mut()
+            .find(|(changed, _)| changed == &field)
+        {
+            change.json = change.current.downcast_ref::<T>().map(serde_json::to_value);
+        }
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.changes.len()
+    }
+
+    pub fn contains(&self, field: F) -> bool {
+        self.changes.iter().any(|(changed, _)| changed == &field)
+    }
+
+    /// The changed fields, in the order they were added to the changeset.
+    pub fn changed_fields(&self) -> Vec<F> {
+        self.changes.iter().map(|(field, _)| *field).collect()
+    }
+
+    /// Returns the change to `field`, or None if it didn't change or its values aren't of type `T`.
+    pub fn change<T: 'static>(&self, field: F) -> Option<Change<'_, T>> {
+        let (_, change) = self.changes.iter().find(|(changed, _)| changed == &field)?;
+        Some(Change {
+            original: change.original.downcast_ref()?,
+            current: change.current.downcast_ref()?,
+        })
+    }
+
+    /// The current value of each changed field, serialized as JSON. Fails if a changed field wasn't added
+    /// with `with_serialized_field`.
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> serde_json::Result<HashMap<F, serde_json::Value>> {
+        self.changes
+            .iter()
+            .map(|(field, change)| match &change.json {
+                Some(Ok(value)) => Ok((*field, value.clone())),
+                // serde_json::Error isn't Clone, so the error is recreated from its description
+                Some(Err(error)) => Err(serde::ser::Error::custom(error)),
+                None => Err(serde::ser::Error::custom(format!(
+                    "{:?} was added to the changeset without being serialized",
+                    field
+                ))),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::forms::testing::Fields;
+
+    #[test]
+    fn changes() {
+        let name = FormStorage::new(String::from("ecton"));
+        let age = FormStorage::new(Some(30u32));
+        let tags = FormStorage::new(vec![String::from("rust")]);
+        let changeset = || {
+            Changeset::default()
+                .with_field(Fields::Name, &name)
+                .with_field(Fields::Age, &age)
+                .with_field(Fields::Tags, &tags)
+        };
+        assert!(changeset().is_empty());
+
+        name.clone().update(String::from("jon"));
+        age.clone().update(None);
+        let changes = changeset();
+        assert_eq!(changes.changed_fields(), vec![Fields::Name, Fields::Age]);
+        assert!(!changes.contains(Fields::Tags));
+        assert_eq!(
+            changes.change::<String>(Fields::Name),
+            Some(Change {
+                original: &String::from("ecton"),
+                current: &String::from("jon"),
+            })
+        );
+        assert_eq!(changes.change::<u32>(Fields::Age), None);
+        assert_eq!(
+            changes.change::<Option<u32>>(Fields::Age).unwrap().current,
+            &None
+        );
+
+        // Changing a value back to its original removes it from the changeset
+        name.clone().update(String::from("ecton"));
+        assert_eq!(changeset().changed_fields(), vec![Fields::Age]);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn json() {
+        let name = FormStorage::new(String::from("ecton"));
+        let age = FormStorage::new(Some(30u32));
+        name.clone().update(String::from("jon"));
+        age.clone().update(None);
+
+        let changes = Changeset::default()
+            .with_serialized_field(Fields::Name, &name)
+            .with_serialized_field(Fields::Age, &age);
+        let json = changes.to_json().unwrap();
+        assert_eq!(json.len(), 2);
+        assert_eq!(json[&Fields::Name], serde_json::json!("jon"));
+        assert_eq!(json[&Fields::Age], serde_json::Value::Null);
+
+        let changes = changes.with_field(Fields::Age, &age);
+        assert!(changes.to_json().is_err());
+    }
+}
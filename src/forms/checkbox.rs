@@ -0,0 +1,91 @@
+use super::storage::FormStorage;
+use crate::forms::FormField;
+use std::{collections::HashMap, rc::Rc};
+use yew::prelude::*;
+
+pub struct Checkbox<T>
+where
+    T: FormField,
+{
+    props: Props<T>,
+    link: ComponentLink<Self>,
+}
+
+#[derive(Clone, Properties)]
+pub struct Props<T>
+where
+    T: FormField,
+{
+    #[prop_or_default]
+    pub on_value_changed: Callback<bool>,
+    pub storage: FormStorage<bool>,
+    pub field: T,
+    pub errors: Option<Rc<HashMap<T, Vec<Rc<Html>>>>>,
+    #[prop_or_default]
+    pub label: String,
+    #[prop_or_default]
+    pub disabled: bool,
+}
+
+pub enum Message {
+    Toggled,
+    Blurred,
+}
+
+impl<T> Component for Checkbox<T>
+where
+    T: FormField,
+{
+    type Message = Message;
+    type Properties = Props<T>;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self { props, link }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Message::Blurred => self.props.storage.touch(),
+            Message::Toggled => {
+                let checked = !self.props.storage.unchecked_value();
+                self.props.storage.update(checked);
+                self.props.on_value_changed.emit(checked);
+            }
+        }
+        true
+    }
+
+    fn view(&self) -> Html {
+        let has_errors = self
+            .props
+            .errors
+            .as_ref()
+            .map(|errors| errors.contains_key(&self.props.field))
+            .unwrap_or_default();
+        let css_class = if has_errors {
+            "control is-danger"
+        } else {
+            "control"
+        };
+        html! {
+            <div class=css_class>
+                <label class="checkbox">
+                    <input
+                        id=self.props.field.form_id()
+                        type="checkbox"
+                        checked=self.props.storage.unchecked_value()
+                        onclick=self.link.callback(|_| Message::Toggled)
+                        onblur=self.link.callback(|_| Message::Blurred)
+                        disabled=self.props.disabled />
+                    { " " }
+                    { &self.props.label }
+                </label>
+            </div>
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        self.props = props;
+        true
+    }
+}
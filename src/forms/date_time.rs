@@ -0,0 +1,300 @@
+use std::{collections::HashMap, rc::Rc};
+
+use chrono::{DateTime, Datelike, NaiveDate, NaiveDateTime, NaiveTime, Timelike, Utc};
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+use crate::forms::FormField;
+
+use super::storage::FormStorage;
+
+pub type DateInput<T> = TemporalInput<T, NaiveDate>;
+pub type TimeInput<T> = TemporalInput<T, NaiveTime>;
+pub type DateTimeInput<T> = TemporalInput<T, DateTime<Utc>>;
+
+/// A value that can be edited with one of the browser's native date or time inputs.
+pub trait TemporalValue: Copy + PartialOrd + std::fmt::Debug + 'static {
+    fn input_type() -> &'static str;
+    fn parse_input(value: &str) -> Option<Self>;
+    fn format_input(&self) -> String;
+}
+
+impl TemporalValue for NaiveDate {
+    fn input_type() -> &'static str {
+        "date"
+    }
+
+    fn parse_input(value: &str) -> Option<Self> {
+        NaiveDate::parse_from_str(value, "%Y-%m-%d").ok()
+    }
+
+    fn format_input(&self) -> String {
+        self.format("%Y-%m-%d").to_string()
+    }
+}
+
+impl TemporalValue for NaiveTime {
+    fn input_type() -> &'static str {
+        "time"
+    }
+
+    fn parse_input(value: &str) -> Option<Self> {
+        // Browsers omit the seconds unless the input's step requires them
+        NaiveTime::parse_from_str(value, "%H:%M:%S%.f")
+            .or_else(|_| NaiveTime::parse_from_str(value, "%H:%M"))
+            .ok()
+    }
+
+    fn format_input(&self) -> String {
+        if self.second() == 0 {
+            self.format("%H:%M").to_string()
+        } else {
+            self.format("%H:%M:%S").to_string()
+        }
+    }
+}
+
+/// Edited through a `datetime-local` input, converting between the browser's local time and UTC.
+impl TemporalValue for DateTime<Utc> {
+    fn input_type() -> &'static str {
+        "datetime-local"
+    }
+
+    fn parse_input(value: &str) -> Option<Self> {
+        NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S%.f")
+            .or_else(|_| NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M"))
+            .ok()
+            .and_then(local_to_utc)
+    }
+
+    fn format_input(&self) -> String {
+        let local = utc_to_local(self);
+        if local.second() == 0 {
+            local.format("%Y-%m-%dT%H:%M").to_string()
+        } else {
+            local.format("%Y-%m-%dT%H:%M:%S").to_string()
+        }
+    }
+}
+
+/// Interprets `local` in the browser's time zone.
+fn local_to_utc(local: NaiveDateTime) -> Option<DateTime<Utc>> {
+    let date = js_sys::Date::new_with_year_month_day_hr_min_sec(
+        local.year() as u32,
+        local.month0() as i32,
+        local.day() as i32,
+        local.hour() as i32,
+        local.minute() as i32,
+        local.second() as i32,
+    );
+    DateTime::from_timestamp_millis(date.get_time() as i64)
+}
+
+/// Converts `utc` to the browser's time zone.
+fn utc_to_local(utc: &DateTime<Utc>) -> NaiveDateTime {
+    let date = js_sys::Date::new_0();
+    date.set_time(utc.timestamp_millis() as f64);
+    NaiveDate::from_ymd_opt(
+        date.get_full_year() as i32,
+        date.get_month() + 1,
+        date.get_date(),
+    )
+    .and_then(|day| day.and_hms_opt(date.get_hours(), date.get_minutes(), date.get_seconds()))
+    .unwrap_or_else(|| utc.naive_utc())
+}
+
+fn within_bounds<V: PartialOrd>(value: &V, min: Option<&V>, max: Option<&V>) -> bool {
+    min.map(|min| value >= min).unwrap_or(true) && max.map(|max| value <= max).unwrap_or(true)
+}
+
+pub struct TemporalInput<T, V>
+where
+    T: FormField,
+    V: TemporalValue,
+{
+    props: Props<T, V>,
+    text_value: String,
+    input: NodeRef,
+    link: ComponentLink<Self>,
+}
+
+#[derive(Clone, Properties)]
+pub struct Props<T, V>
+where
+    T: FormField,
+    V: TemporalValue,
+{
+    #[prop_or_default]
+    pub on_value_changed: Callback<Option<V>>,
+    pub storage: FormStorage<Option<V>>,
+    pub field: T,
+    pub errors: Option<Rc<HashMap<T, Vec<Rc<Html>>>>>,
+    /// Values before `min` are flagged as invalid.
+    #[prop_or_default]
+    pub min: Option<V>,
+    /// Values after `max` are flagged as invalid.
+    #[prop_or_default]
+    pub max: Option<V>,
+    #[prop_or_default]
+    pub disabled: bool,
+    #[prop_or_default]
+    pub readonly: bool,
+    #[prop_or_default]
+    pub autofocus: bool,
+}
+
+pub enum Message {
+    ValueChanged,
+    Blurred,
+}
+
+impl<T, V> Component for TemporalInput<T, V>
+where
+    T: FormField,
+    V: TemporalValue,
+{
+    type Message = Message;
+    type Properties = Props<T, V>;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let text_value = props
+            .storage
+            .unchecked_value()
+            .map(|v| v.format_input())
+            .unwrap_or_default();
+        Self {
+            props,
+            link,
+            input: NodeRef::default(),
+            text_value,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Message::Blurred => self.props.storage.touch(),
+            Message::ValueChanged => {
+                if let Some(input) = self.input.cast::<HtmlInputElement>() {
+                    self.text_value = input.value();
+                    if self.text_value.is_empty() {
+                        self.props.storage.update_with_invalid_hint(None, false);
+                        self.props.on_value_changed.emit(None);
+                    } else if let Some(value) = V::parse_input(&self.text_value) {
+                        let in_bounds =
+                            within_bounds(&value, self.props.min.as_ref(), self.props.max.as_ref());
+                        self.props
+                            .storage
+                            .update_with_invalid_hint(Some(value), !in_bounds);
+                        self.props.on_value_changed.emit(Some(value));
+                    } else {
+                        self.props.storage.update_invalid_hint(true);
+                        self.props
+                            .on_value_changed
+                            .emit(self.props.storage.value().unwrap_or_default());
+                    }
+                }
+            }
+        }
+        false
+    }
+
+    fn view(&self) -> Html {
+        let has_errors = self
+            .props
+            .errors
+            .as_ref()
+            .map(|errors| errors.contains_key(&self.props.field))
+            .unwrap_or_default();
+        let css_class = if has_errors {
+            "input is-danger"
+        } else {
+            "input"
+        };
+        let min = self
+            .props
+            .min
+            .map(|min| min.format_input())
+            .unwrap_or_default();
+        let max = self
+            .props
+            .max
+            .map(|max| max.format_input())
+            .unwrap_or_default();
+        html! {
+            <div class="control">
+                <input
+                    id=self.props.field.form_id()
+                    class=css_class
+                    ref=self.input.clone()
+                    type=V::input_type()
+                    value=self.text_value
+                    min=min
+                    max=max
+                    onchange=self.link.callback(|_| Message::ValueChanged)
+                    onblur=self.link.callback(|_| Message::Blurred)
+                    oninput=self.link.callback(|_| Message::ValueChanged)
+                    disabled=self.props.disabled
+                    readonly=self.props.readonly />
+            </div>
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        self.text_value = props
+            .storage
+            .unchecked_value()
+            .map(|v| v.format_input())
+            .unwrap_or_default();
+        self.props = props;
+        true
+    }
+
+    fn rendered(&mut self, first_render: bool) {
+        if first_render && self.props.autofocus {
+            if let Some(input) = self.input.cast::<HtmlInputElement>() {
+                let _ = input.focus();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dates() {
+        let date = NaiveDate::from_ymd_opt(2020, 2, 29).unwrap();
+        assert_eq!(date.format_input(), "2020-02-29");
+        assert_eq!(NaiveDate::parse_input("2020-02-29"), Some(date));
+        assert_eq!(NaiveDate::parse_input("2021-02-29"), None);
+        assert_eq!(NaiveDate::parse_input(""), None);
+    }
+
+    #[test]
+    fn times() {
+        let time = NaiveTime::from_hms_opt(13, 5, 0).unwrap();
+        assert_eq!(time.format_input(), "13:05");
+        assert_eq!(NaiveTime::parse_input("13:05"), Some(time));
+        let time = NaiveTime::from_hms_opt(13, 5, 9).unwrap();
+        assert_eq!(time.format_input(), "13:05:09");
+        assert_eq!(NaiveTime::parse_input("13:05:09"), Some(time));
+        assert_eq!(NaiveTime::parse_input("25:00"), None);
+    }
+
+    #[test]
+    fn bounds() {
+        let min = NaiveDate::from_ymd_opt(2020, 1, 1).unwrap();
+        let max = NaiveDate::from_ymd_opt(2020, 12, 31).unwrap();
+        let inside = NaiveDate::from_ymd_opt(2020, 6, 1).unwrap();
+        let before = NaiveDate::from_ymd_opt(2019, 12, 31).unwrap();
+        let after = NaiveDate::from_ymd_opt(2021, 1, 1).unwrap();
+        assert!(within_bounds(&inside, Some(&min), Some(&max)));
+        assert!(within_bounds(&min, Some(&min), Some(&max)));
+        assert!(within_bounds(&max, Some(&min), Some(&max)));
+        assert!(!within_bounds(&before, Some(&min), Some(&max)));
+        assert!(!within_bounds(&after, Some(&min), Some(&max)));
+        assert!(within_bounds(&before, None, Some(&max)));
+        assert!(within_bounds(&after, Some(&min), None));
+    }
+}
@@ -1,5 +1,5 @@
 use super::label::Label;
-use crate::forms::FormField;
+use crate::{forms::FormField, validations::Severity};
 use std::{collections::HashMap, rc::Rc};
 use yew::prelude::*;
 
@@ -17,10 +17,18 @@ where
 {
     pub field: T,
     pub errors: Option<Rc<HashMap<T, Vec<Rc<Html>>>>>,
+    /// Messages that don't prevent submission, from `ErrorSet::translate_severity(Severity::Warning, ..)`.
+    #[prop_or_default]
+    pub warnings: Option<Rc<HashMap<T, Vec<Rc<Html>>>>>,
+    #[prop_or_default]
+    pub info: Option<Rc<HashMap<T, Vec<Rc<Html>>>>>,
     #[prop_or_default]
     pub label: String,
     #[prop_or_default]
     pub help: String,
+    /// Shows a spinner while an `AsyncValidation` for this field is running.
+    #[prop_or_default]
+    pub pending: bool,
     #[prop_or_default]
     pub children: Children,
 }
@@ -51,27 +59,24 @@ where
         } else {
             html! {}
         };
-        let errors = self.props.errors.as_ref().map(|errors| {
-            errors.get(&self.props.field).map(|errors| {
-                errors
-                    .iter()
-                    .map(|e| html! {<p class="help is-danger">{e.as_ref().clone()}</p>})
-                    .collect::<Html>()
-            })
-        });
-
-        let error_message = match errors {
-            Some(errors) => match errors {
-                Some(field_errors) => field_errors,
-                None => Html::default(),
-            },
-            None => Html::default(),
+        let error_message = self.render_messages(self.props.errors.as_deref(), Severity::Error);
+        let warning_message =
+            self.render_messages(self.props.warnings.as_deref(), Severity::Warning);
+        let info_message = self.render_messages(self.props.info.as_deref(), Severity::Info);
+        // Bulma places the spinner within a control. The wrapper is always rendered, so that toggling
+        // `pending` only changes its class rather than recreating the inputs and losing their focus.
+        let control_class = if self.props.pending {
+            "control is-loading"
+        } else {
+            "control"
         };
         html! {
             <div class="field">
                 { label }
-                { self.props.children.clone() }
+                <div class=control_class>{ self.props.children.clone() }</div>
                 { error_message }
+                { warning_message }
+                { info_message }
                 { help }
             </div>
         }
@@ -81,3 +86,24 @@ where
         true
     }
 }
+
+impl<T> Field<T>
+where
+    T: FormField,
+{
+    fn render_messages(
+        &self,
+        messages: Option<&HashMap<T, Vec<Rc<Html>>>>,
+        severity: Severity,
+    ) -> Html {
+        messages
+            .and_then(|messages| messages.get(&self.props.field))
+            .map(|messages| {
+                messages
+                    .iter()
+                    .map(|m| html! {<p class=format!("help {}", severity.css_class())>{m.as_ref().clone()}</p>})
+                    .collect::<Html>()
+            })
+            .unwrap_or_default()
+    }
+}
@@ -0,0 +1,331 @@
+use std::{cell::RefCell, rc::Rc};
+
+use yew::prelude::*;
+
+use crate::validations::{ValidationError, Validator};
+
+use super::storage::FormStorage;
+
+/// A list of `FormStorage`s for repeatable rows, such as a list of addresses.
+///
+/// Validation errors for a row can be reported on an indexed field using
+/// `ModelValidator::with_indexed`.
+#[derive(Debug, Default, Clone)]
+pub struct FieldArray<T>
+where
+    T: std::fmt::Debug + Default + Clone,
+{
+    backing: Rc<RefCell<FieldArrayBacking<T>>>,
+}
+
+#[derive(Debug, Default)]
+struct FieldArrayBacking<T>
+where
+    T: std::fmt::Debug + Default + Clone,
+{
+    /// Each row is paired with an id that is stable across removals and moves, used to key rendered rows.
+    rows: Vec<(usize, FormStorage<T>)>,
+    original_values: Vec<T>,
+    last_row_id: usize,
+}
+
+impl<T> FieldArray<T>
+where
+    T: std::fmt::Debug + Default + Clone + PartialEq,
+{
+    pub fn new(values: Vec<T>) -> Self {
+        let array = Self {
+            backing: Rc::new(RefCell::new(FieldArrayBacking {
+                rows: Vec::new(),
+                original_values: values.clone(),
+                last_row_id: 0,
+            })),
+        };
+        for value in values {
+            array.push(value);
+        }
+        array
+    }
+
+    /// Appends a row, returning its storage.
+    pub fn push(&self, value: T) -> FormStorage<T> {
+        let mut backing = self.backing.borrow_mut();
+        backing.last_row_id += 1;
+        let id = backing.last_row_id;
+        let storage = FormStorage::new(value);
+        backing.rows.push((id, storage.clone()));
+        storage
+    }
+
+    /// Removes the row at `index`, returning its storage if it existed.
+    pub fn remove(&self, index: usize) -> Option<FormStorage<T>> {
+        let mut backing = self.backing.borrow_mut();
+        if index < backing.rows.len() {
+            Some(backing.rows.remove(index).1)
+        } else {
+            None
+        }
+    }
+
+    /// Moves the row at `from` so that it ends up at `to`, shifting the rows in between. Returns false if either
+    /// index is out of bounds.
+    pub fn move_row(&self, from: usize, to: usize) -> bool {
+        let mut backing = self.backing.borrow_mut();
+        if from >= backing.rows.len() || to >= backing.rows.len() {
+            return false;
+        }
+        let row = backing.rows.remove(from);
+        backing.rows.insert(to, row);
+        true
+    }
+
+    pub fn len(&self) -> usize {
+        self.backing.borrow().rows.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.backing.borrow().rows.is_empty()
+    }
+
+    pub fn get(&self, index: usize) -> Option<FormStorage<T>> {
+        self.backing
+            .borrow()
+            .rows
+            .get(index)
+            .map(|(_, storage)| storage.clone())
+    }
+
+    pub fn rows(&self) -> Vec<FormStorage<T>> {
+        self.backing
+            .borrow()
+            .rows
+            .iter()
+            .map(|(_, storage)| storage.clone())
+            .collect()
+    }
+
+    pub(crate) fn keyed_rows(&self) -> Vec<(usize, FormStorage<T>)> {
+        self.backing.borrow().rows.clone()
+    }
+
+    /// Returns the value of each row, or the first row's validation error.
+    pub fn values(&self) -> Result<Vec<T>, ValidationError> {
+        self.rows().iter().map(FormStorage::value).collect()
+    }
+
+    pub fn unchecked_values(&self) -> Vec<T> {
+        self.rows()
+            .iter()
+            .map(FormStorage::unchecked_value)
+            .collect()
+    }
+
+    /// Returns true if rows were added, removed or moved, or if any row is dirty.
+    pub fn is_dirty(&self) -> bool {
+        let rows = self.rows();
+        rows.iter().any(FormStorage::is_dirty)
+            || rows.iter().map(FormStorage::unchecked_value).ne(self
+                .backing
+                .borrow()
+                .original_values
+                .iter()
+                .cloned())
+    }
+}
+
+impl<T> Validator for FieldArray<T>
+where
+    T: std::fmt::Debug + Default + Clone + PartialEq,
+{
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.rows().iter().try_for_each(Validator::validate)
+    }
+}
+
+/// Renders a row of a `FieldArrayInput` given its index and storage.
+pub type RowTemplate<T> = Rc<dyn Fn(usize, FormStorage<T>) -> Html>;
+
+/// Renders a row per entry in a `FieldArray` using `row`, with buttons to add and remove rows.
+pub struct FieldArrayInput<T>
+where
+    T: std::fmt::Debug + Default + Clone + PartialEq + 'static,
+{
+    props: Props<T>,
+    link: ComponentLink<Self>,
+}
+
+#[derive(Clone, Properties)]
+pub struct Props<T>
+where
+    T: std::fmt::Debug + Default + Clone + PartialEq + 'static,
+{
+    #[prop_or_default]
+    pub on_value_changed: Callback<Vec<T>>,
+    pub storage: FieldArray<T>,
+    pub row: RowTemplate<T>,
+    #[prop_or("Add".to_owned())]
+    pub add_label: String,
+    #[prop_or("Remove".to_owned())]
+    pub remove_label: String,
+    /// Rows can't be removed once this many remain.
+    #[prop_or_default]
+    pub min_rows: usize,
+    /// Rows can't be added once this many exist.
+    #[prop_or_default]
+    pub max_rows: Option<usize>,
+    #[prop_or_default]
+    pub disabled: bool,
+}
+
+pub enum Message {
+    Add,
+    Remove(usize),
+}
+
+impl<T> Component for FieldArrayInput<T>
+where
+    T: std::fmt::Debug + Default + Clone + PartialEq + 'static,
+{
+    type Message = Message;
+    type Properties = Props<T>;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self { props, link }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Message::Add => {
+                let _ = self.props.storage.push(T::default());
+            }
+            Message::Remove(index) => {
+                self.props.storage.remove(index);
+            }
+        }
+        self.props
+            .on_value_changed
+            .emit(self.props.storage.unchecked_values());
+        true
+    }
+
+    fn view(&self) -> Html {
+        let rows = self.props.storage.keyed_rows();
+        let can_remove = !self.props.disabled && rows.len() > self.props.min_rows;
+        let can_add = !self.props.disabled
+            && self
+                .props
+                .max_rows
+                .map(|max_rows| rows.len() < max_rows)
+                .unwrap_or(true);
+        html! {
+            <div class="field-array">
+                { rows.into_iter().enumerate().map(|(index, (id, storage))| html! {
+                    <div class="field is-grouped" key=id.to_string()>
+                        <div class="control is-expanded">
+                            { (self.props.row)(index, storage) }
+                        </div>
+                        <div class="control">
+                            <button
+                                class="button is-danger is-light"
+                                type="button"
+                                disabled=!can_remove
+                                onclick=self.link.callback(move |_| Message::Remove(index))>
+                                { &self.props.remove_label }
+                            </button>
+                        </div>
+                    </div>
+                }).collect::<Html>() }
+                <div class="field">
+                    <div class="control">
+                        <button
+                            class="button"
+                            type="button"
+                            disabled=!can_add
+                            onclick=self.link.callback(|_| Message::Add)>
+                            { &self.props.add_label }
+                        </button>
+                    </div>
+                </div>
+            </div>
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        self.props = props;
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validations::{ModelValidator, Validatable};
+
+    #[test]
+    fn rows() {
+        let array = FieldArray::new(vec![1, 2, 3]);
+        assert!(!array.is_dirty());
+
+        array.push(4);
+        assert_eq!(array.unchecked_values(), vec![1, 2, 3, 4]);
+        assert!(array.is_dirty());
+
+        assert_eq!(array.remove(3).unwrap().unchecked_value(), 4);
+        assert!(array.remove(3).is_none());
+        assert!(!array.is_dirty());
+
+        assert!(array.move_row(0, 2));
+        assert_eq!(array.unchecked_values(), vec![2, 3, 1]);
+        assert!(!array.move_row(0, 3));
+        assert!(array.move_row(2, 0));
+        assert!(!array.is_dirty());
+
+        array.get(1).unwrap().update(5);
+        assert_eq!(array.values().unwrap(), vec![1, 5, 3]);
+        assert!(array.is_dirty());
+    }
+
+    #[derive(Debug, Clone, Copy, Hash, Eq, PartialEq)]
+    enum PhoneField {
+        Number,
+    }
+
+    #[derive(Debug, Clone, Copy, Hash, Eq, PartialEq)]
+    enum ContactField {
+        Name,
+        Phones(usize, PhoneField),
+    }
+
+    #[test]
+    fn indexed_errors() {
+        let phones = FieldArray::new(vec![
+            Some(String::from("555-1234")),
+            None,
+            Some(String::from("555-4321")),
+            None,
+        ]);
+        let errors = ModelValidator::default()
+            .with_field(ContactField::Name, Some(String::from("jon")).is_present())
+            .with_indexed(
+                &phones,
+                |phone| {
+                    ModelValidator::default().with_field(PhoneField::Number, phone.is_present())
+                },
+                ContactField::Phones,
+            )
+            .validate()
+            .unwrap();
+        let fields = errors
+            .errors()
+            .iter()
+            .map(|error| error.primary_field())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            fields,
+            vec![
+                ContactField::Phones(1, PhoneField::Number),
+                ContactField::Phones(3, PhoneField::Number)
+            ]
+        );
+    }
+}
@@ -0,0 +1,382 @@
+use std::{collections::HashMap, rc::Rc};
+
+use thiserror::Error;
+use web_sys::{File, FileList, HtmlInputElement, Url};
+use yew::prelude::*;
+
+use crate::forms::FormField;
+
+use super::storage::FormStorage;
+
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum FileRejection {
+    #[error("is larger than {max_size} bytes")]
+    TooLarge { size: u64, max_size: u64 },
+    #[error("is not an accepted file type")]
+    NotAccepted,
+}
+
+/// A file selected through a `FileInput`. Rejected files are kept so that they can be reported by
+/// `ValidatableFiles::are_files_accepted`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileHandle {
+    pub file: File,
+    pub preview_url: Option<Rc<ObjectUrl>>,
+    pub rejection: Option<FileRejection>,
+}
+
+/// A URL created with `URL.createObjectURL()`, revoked once the last handle referencing it is dropped. Handles
+/// outlive the `FileInput` that created them, such as in a `FormHistory`, so previews stay valid while they do.
+#[derive(Debug, PartialEq)]
+pub struct ObjectUrl(String);
+
+impl ObjectUrl {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Drop for ObjectUrl {
+    fn drop(&mut self) {
+        let _ = Url::revoke_object_url(&self.0);
+    }
+}
+
+impl FileHandle {
+    pub fn name(&self) -> String {
+        self.file.name()
+    }
+
+    pub fn size(&self) -> u64 {
+        self.file.size() as u64
+    }
+
+    pub fn mime_type(&self) -> String {
+        self.file.type_()
+    }
+}
+
+/// Checks a file against an `accept` attribute value, such as `image/*,.pdf`.
+pub fn is_accepted(accept: &str, name: &str, mime_type: &str) -> bool {
+    let name = name.to_lowercase();
+    let mime_type = mime_type.to_lowercase();
+    let mut patterns = accept
+        .split(',')
+        .map(|pattern| pattern.trim().to_lowercase())
+        .filter(|pattern| !pattern.is_empty())
+        .peekable();
+    if patterns.peek().is_none() {
+        return true;
+    }
+
+    patterns.any(|pattern| {
+        if pattern.starts_with('.') {
+            name.ends_with(&pattern)
+        } else if let Some(prefix) = pattern.strip_suffix("/*") {
+            mime_type
+                .split('/')
+                .next()
+                .map(|kind| kind == prefix)
+                .unwrap_or_default()
+        } else {
+            mime_type == pattern
+        }
+    })
+}
+
+fn rejection_for(
+    accept: &str,
+    max_size: Option<u64>,
+    name: &str,
+    mime_type: &str,
+    size: u64,
+) -> Option<FileRejection> {
+    if !is_accepted(accept, name, mime_type) {
+        return Some(FileRejection::NotAccepted);
+    }
+    match max_size {
+        Some(max_size) if size > max_size => Some(FileRejection::TooLarge { size, max_size }),
+        _ => None,
+    }
+}
+
+pub struct FileInput<T>
+where
+    T: FormField,
+{
+    props: Props<T>,
+    dragging: bool,
+    input: NodeRef,
+    link: ComponentLink<Self>,
+}
+
+#[derive(Clone, Properties)]
+pub struct Props<T>
+where
+    T: FormField,
+{
+    #[prop_or_default]
+    pub on_value_changed: Callback<Vec<FileHandle>>,
+    pub storage: FormStorage<Vec<FileHandle>>,
+    pub field: T,
+    pub errors: Option<Rc<HashMap<T, Vec<Rc<Html>>>>>,
+    #[prop_or("Choose a file…".to_owned())]
+    pub label: String,
+    /// The same format as the `accept` attribute, such as `image/*,.pdf`.
+    #[prop_or_default]
+    pub accept: String,
+    /// The maximum size of each file, in bytes.
+    #[prop_or_default]
+    pub max_size: Option<u64>,
+    #[prop_or_default]
+    pub multiple: bool,
+    #[prop_or_default]
+    pub disabled: bool,
+    /// Additional classes for the `file` wrapper, such as `is-boxed` or `is-primary`.
+    #[prop_or_default]
+    pub css_class: String,
+}
+
+pub enum Message {
+    Blurred,
+    FilesSelected,
+    DragOver(DragEvent),
+    DragLeave,
+    Drop(DragEvent),
+    Remove(usize),
+}
+
+impl<T> Component for FileInput<T>
+where
+    T: FormField,
+{
+    type Message = Message;
+    type Properties = Props<T>;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            props,
+            link,
+            dragging: false,
+            input: NodeRef::default(),
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Message::Blurred => {
+                self.props.storage.touch();
+                true
+            }
+            Message::FilesSelected => {
+                if let Some(input) = self.input.cast::<HtmlInputElement>() {
+                    if let Some(files) = input.files() {
+                        self.add_files(&files);
+                    }
+                    // Otherwise choosing the same file again, such as after removing it, doesn't fire `onchange`
+                    input.set_value("");
+                }
+                true
+            }
+            Message::DragOver(event) => {
+                event.prevent_default();
+                let was_dragging = self.dragging;
+                self.dragging = !self.props.disabled;
+                !was_dragging && self.dragging
+            }
+            Message::DragLeave => {
+                self.dragging = false;
+                true
+            }
+            Message::Drop(event) => {
+                event.prevent_default();
+                self.dragging = false;
+                if self.props.disabled {
+                    return true;
+                }
+                if let Some(files) = event.data_transfer().and_then(|transfer| transfer.files()) {
+                    self.add_files(&files);
+                }
+                true
+            }
+            Message::Remove(index) => {
+                let mut handles = self.props.storage.unchecked_value();
+                if index < handles.len() {
+                    handles.remove(index);
+                    self.store(handles);
+                }
+                true
+            }
+        }
+    }
+
+    fn view(&self) -> Html {
+        let handles = self.props.storage.unchecked_value();
+        let has_errors = self
+            .props
+            .errors
+            .as_ref()
+            .map(|errors| errors.contains_key(&self.props.field))
+            .unwrap_or_default();
+        let mut css_class = format!("file has-name {}", self.props.css_class);
+        if has_errors {
+            css_class.push_str(" is-danger");
+        }
+        if self.dragging {
+            css_class.push_str(" is-primary");
+        }
+        let file_names = handles
+            .iter()
+            .map(|handle| handle.name())
+            .collect::<Vec<_>>()
+            .join(", ");
+        html! {
+            <div class="control">
+                <div
+                    class=css_class
+                    ondragover=self.link.callback(Message::DragOver)
+                    ondragleave=self.link.callback(|_| Message::DragLeave)
+                    ondrop=self.link.callback(Message::Drop)>
+                    <label class="file-label">
+                        <input
+                            id=self.props.field.form_id()
+                            class="file-input"
+                            type="file"
+                            ref=self.input.clone()
+                            accept=&self.props.accept
+                            multiple=self.props.multiple
+                            disabled=self.props.disabled
+                            onchange=self.link.callback(|_| Message::FilesSelected)
+                            onblur=self.link.callback(|_| Message::Blurred) />
+                        <span class="file-cta">
+                            <span class="file-icon"><i class="fas fa-upload" /></span>
+                            <span class="file-label">{ &self.props.label }</span>
+                        </span>
+                        <span class="file-name">{ file_names }</span>
+                    </label>
+                </div>
+                { handles.iter().enumerate().map(|(index, handle)| self.render_file(index, handle)).collect::<Html>() }
+            </div>
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        self.props = props;
+        true
+    }
+}
+
+impl<T> FileInput<T>
+where
+    T: FormField,
+{
+    fn add_files(&mut self, files: &FileList) {
+        let mut handles = if self.props.multiple {
+            self.props.storage.unchecked_value()
+        } else {
+            Vec::new()
+        };
+        for file in (0..files.length()).filter_map(|index| files.get(index)) {
+            let rejection = rejection_for(
+                &self.props.accept,
+                self.props.max_size,
+                &file.name(),
+                &file.type_(),
+                file.size() as u64,
+            );
+            let preview_url = if rejection.is_none() && file.type_().starts_with("image/") {
+                Url::create_object_url_with_blob(&file)
+                    .ok()
+                    .map(|url| Rc::new(ObjectUrl(url)))
+            } else {
+                None
+            };
+            handles.push(FileHandle {
+                file,
+                preview_url,
+                rejection,
+            });
+            if !self.props.multiple {
+                break;
+            }
+        }
+        self.store(handles);
+    }
+
+    fn store(&mut self, handles: Vec<FileHandle>) {
+        let rejected = handles.iter().any(|handle| handle.rejection.is_some());
+        self.props
+            .storage
+            .update_with_invalid_hint(handles.clone(), rejected);
+        self.props.on_value_changed.emit(handles);
+    }
+
+    fn render_file(&self, index: usize, handle: &FileHandle) -> Html {
+        let thumbnail = match &handle.preview_url {
+            Some(url) => html! {
+                <figure class="media-left">
+                    <p class="image is-64x64">
+                        <img src=url.as_str() alt=handle.name() />
+                    </p>
+                </figure>
+            },
+            None => Html::default(),
+        };
+        let rejection = match &handle.rejection {
+            Some(rejection) => html! {<p class="help is-danger">{ rejection.to_string() }</p>},
+            None => Html::default(),
+        };
+        html! {
+            <article class="media">
+                { thumbnail }
+                <div class="media-content">
+                    <p>{ handle.name() }</p>
+                    { rejection }
+                </div>
+                <div class="media-right">
+                    <button
+                        class="delete"
+                        type="button"
+                        aria-label="remove"
+                        disabled=self.props.disabled
+                        onclick=self.link.callback(move |_| Message::Remove(index)) />
+                </div>
+            </article>
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accept_patterns() {
+        assert!(is_accepted("", "photo.png", "image/png"));
+        assert!(is_accepted("image/*", "photo.png", "image/png"));
+        assert!(!is_accepted("image/*", "report.pdf", "application/pdf"));
+        assert!(is_accepted(
+            "image/*, .pdf",
+            "report.PDF",
+            "application/pdf"
+        ));
+        assert!(is_accepted("application/pdf", "report", "application/pdf"));
+        assert!(!is_accepted("application/pdf", "report.pdf", "text/plain"));
+    }
+
+    #[test]
+    fn rejections() {
+        assert_eq!(rejection_for("", Some(10), "a.txt", "text/plain", 10), None);
+        assert_eq!(
+            rejection_for("", Some(10), "a.txt", "text/plain", 11),
+            Some(FileRejection::TooLarge {
+                size: 11,
+                max_size: 10
+            })
+        );
+        assert_eq!(
+            rejection_for("image/*", None, "a.txt", "text/plain", 11),
+            Some(FileRejection::NotAccepted)
+        );
+    }
+}
@@ -0,0 +1,182 @@
+use std::rc::Rc;
+
+use wasm_bindgen::JsCast;
+use web_sys::HtmlElement;
+use yew::prelude::*;
+
+use crate::{
+    forms::{button::Button, FormField},
+    validations::{ErrorSet, ModelValidator},
+};
+
+/// Builds the validator run each time the form is submitted.
+pub type ValidatorFactory<F> = Rc<dyn Fn() -> ModelValidator<F>>;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum SubmissionState {
+    Idle,
+    Validating,
+    Submitting,
+    Succeeded,
+    Failed,
+}
+
+impl SubmissionState {
+    pub fn is_processing(&self) -> bool {
+        matches!(
+            self,
+            SubmissionState::Validating | SubmissionState::Submitting
+        )
+    }
+}
+
+/// Wraps fields in a `<form>` and owns the submission lifecycle. Submitting validates the form,
+/// focusing the first invalid field on failure, and otherwise invokes `on_submit` with a callback
+/// that must be called once the submission has completed.
+pub struct Form<F>
+where
+    F: FormField,
+{
+    props: Props<F>,
+    state: SubmissionState,
+    failure: Option<String>,
+    link: ComponentLink<Self>,
+}
+
+#[derive(Clone, Properties)]
+pub struct Props<F>
+where
+    F: FormField,
+{
+    pub on_submit: Callback<Callback<Result<(), String>>>,
+    #[prop_or_default]
+    pub validator: Option<ValidatorFactory<F>>,
+    /// Invoked with the result of each validation, `None` meaning the form is valid.
+    #[prop_or_default]
+    pub on_errors: Callback<Option<Rc<ErrorSet<F>>>>,
+    #[prop_or_default]
+    pub on_state_changed: Callback<SubmissionState>,
+    pub submit_label: String,
+    #[prop_or("is-primary".to_owned())]
+    pub submit_css_class: String,
+    #[prop_or_default]
+    pub disabled: bool,
+    #[prop_or_default]
+    pub children: Children,
+}
+
+pub enum Message {
+    Submit,
+    Completed(Result<(), String>),
+}
+
+impl<F> Component for Form<F>
+where
+    F: FormField,
+{
+    type Message = Message;
+    type Properties = Props<F>;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            props,
+            link,
+            state: SubmissionState::Idle,
+            failure: None,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Message::Submit => {
+                if self.props.disabled || self.state.is_processing() {
+                    return false;
+                }
+
+                self.failure = None;
+                self.set_state(SubmissionState::Validating);
+                let errors = self
+                    .props
+                    .validator
+                    .as_ref()
+                    .and_then(|validator| validator().validate());
+                self.props.on_errors.emit(errors.clone());
+                // Warnings and informational messages are shown without preventing submission
+                if let Some(errors) = errors.filter(|errors| errors.is_blocking()) {
+                    if let Some(error) = errors.errors().iter().find(|error| error.is_blocking()) {
+                        focus_field(error.primary_field());
+                    }
+                    self.set_state(SubmissionState::Idle);
+                } else {
+                    self.set_state(SubmissionState::Submitting);
+                    self.props
+                        .on_submit
+                        .emit(self.link.callback(Message::Completed));
+                }
+            }
+            Message::Completed(result) => {
+                if self.state != SubmissionState::Submitting {
+                    return false;
+                }
+
+                match result {
+                    Ok(_) => self.set_state(SubmissionState::Succeeded),
+                    Err(message) => {
+                        self.failure = Some(message);
+                        self.set_state(SubmissionState::Failed);
+                    }
+                }
+            }
+        }
+        true
+    }
+
+    fn view(&self) -> Html {
+        let failure = match &self.failure {
+            Some(message) if !message.is_empty() => {
+                html! {<p class="help is-danger">{ message }</p>}
+            }
+            _ => Html::default(),
+        };
+        html! {
+            <form onsubmit=self.link.callback(|e: FocusEvent| {e.prevent_default(); Message::Submit})>
+                { self.props.children.clone() }
+                <div class="field is-grouped">
+                    <Button
+                        label=&self.props.submit_label
+                        css_class=&self.props.submit_css_class
+                        disabled=self.props.disabled || self.state.is_processing()
+                        processing=self.state.is_processing()
+                        action=self.link.callback(|e: MouseEvent| {e.prevent_default(); Message::Submit})
+                    />
+                </div>
+                { failure }
+            </form>
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        self.props = props;
+        true
+    }
+}
+
+impl<F> Form<F>
+where
+    F: FormField,
+{
+    fn set_state(&mut self, state: SubmissionState) {
+        self.state = state;
+        self.props.on_state_changed.emit(state);
+    }
+}
+
+pub(crate) fn focus_field<F: FormField>(field: F) {
+    let element = web_sys::window()
+        .and_then(|window| window.document())
+        .and_then(|document| document.get_element_by_id(&field.form_id()))
+        .and_then(|element| element.dyn_into::<HtmlElement>().ok());
+    if let Some(element) = element {
+        let _ = element.focus();
+    }
+}
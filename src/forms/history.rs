@@ -0,0 +1,401 @@
+use std::{cell::RefCell, rc::Rc, time::Duration};
+
+use chrono::{DateTime, Utc};
+use yew::Callback;
+
+use super::storage::{FormStorage, SubscriptionHandle};
+
+/// Returns the current time, used to decide whether consecutive changes should be coalesced.
+pub type Clock = Rc<dyn Fn() -> DateTime<Utc>>;
+
+/// Changes to the same storage made within this window of each other are undone as a single step.
+pub const DEFAULT_COALESCE_WINDOW: Duration = Duration::from_millis(1000);
+
+/// Records changes made to one or more `FormStorage`s so that they can be undone and redone.
+///
+/// Consecutive changes to the same storage made within the coalescing window, such as keystrokes
+/// while typing a word, are recorded as a single step. Changes made within `transaction()` are
+/// recorded as a single step regardless of how many storages they touch.
+#[derive(Clone)]
+pub struct FormHistory {
+    state: Rc<RefCell<HistoryState>>,
+    subscriptions: Rc<RefCell<Vec<SubscriptionHandle>>>,
+}
+
+struct HistoryState {
+    undo: Vec<Vec<Change>>,
+    redo: Vec<Vec<Change>>,
+    transaction: Option<Vec<Change>>,
+    /// Set while undoing or redoing so that the resulting updates aren't recorded.
+    applying: bool,
+    last_storage: Option<usize>,
+    last_change: Option<DateTime<Utc>>,
+    last_storage_id: usize,
+    coalesce_window: chrono::Duration,
+    clock: Clock,
+}
+
+#[derive(Clone)]
+struct Change {
+    storage: usize,
+    undo: Rc<dyn Fn()>,
+    redo: Rc<dyn Fn()>,
+}
+
+impl Default for FormHistory {
+    fn default() -> Self {
+        Self::new(DEFAULT_COALESCE_WINDOW)
+    }
+}
+
+impl std::fmt::Debug for FormHistory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FormHistory")
+            .field("can_undo", &self.can_undo())
+            .field("can_redo", &self.can_redo())
+            .finish()
+    }
+}
+
+impl FormHistory {
+    pub fn new(coalesce_window: Duration) -> Self {
+        Self {
+            state: Rc::new(RefCell::new(HistoryState {
+                undo: Vec::new(),
+                redo: Vec::new(),
+                transaction: None,
+                applying: false,
+                last_storage: None,
+                last_change: None,
+                last_storage_id: 0,
+                coalesce_window: chrono::Duration::from_std(coalesce_window)
+                    .unwrap_or_else(|_| chrono::Duration::zero()),
+                clock: Rc::new(crate::wasm_utc_now),
+            })),
+            subscriptions: Rc::default(),
+        }
+    }
+
+    /// Replaces the source of the current time, which defaults to the browser's clock.
+    pub fn with_clock<C: Fn() -> DateTime<Utc> + 'static>(self, clock: C) -> Self {
+        self.state.borrow_mut().clock = Rc::new(clock);
+        self
+    }
+
+    /// Starts recording changes made to `storage`.
+    pub fn track<T>(&self, storage: &FormStorage<T>)
+    where
+        T: std::fmt::Debug + Default + Clone + PartialEq + 'static,
+    {
+        let id = {
+            let mut state = self.state.borrow_mut();
+            state.last_storage_id += 1;
+            state.last_storage_id
+        };
+        let previous = Rc::new(RefCell::new(storage.unchecked_value()));
+        // The storage holds on to the subscription, so it must only be referenced weakly
+        let weak_storage = Rc::new(storage.downgrade());
+        let state = self.state.clone();
+        let subscription = storage.subscribe(Callback::from(move |value: T| {
+            let old_value = previous.replace(value.clone());
+            // Storages also notify when only their validity changes, which isn't a step to undo
+            if old_value == value || state.borrow().applying {
+                return;
+            }
+
+            let restore = |value: T| {
+                let weak_storage = weak_storage.clone();
+                Rc::new(move || {
+                    if let Some(mut storage) = weak_storage.upgrade() {
+                        storage.update(value.clone());
+                    }
+                }) as Rc<dyn Fn()>
+            };
+            state.borrow_mut().record(Change {
+                storage: id,
+                undo: restore(old_value),
+                redo: restore(value),
+            });
+        }));
+        self.subscriptions.borrow_mut().push(subscription);
+    }
+
+    /// Records every change made while running `changes` as a single step.
+    pub fn transaction<R, F: FnOnce() -> R>(&self, changes: F) -> R {
+        let nested = {
+            let mut state = self.state.borrow_mut();
+            let nested = state.transaction.is_some();
+            if !nested {
+                state.transaction = Some(Vec::new());
+            }
+            nested
+        };
+        let result = changes();
+        if !nested {
+            let mut state = self.state.borrow_mut();
+            if let Some(changes) = state.transaction.take() {
+                if !changes.is_empty() {
+                    state.push(changes);
+                }
+            }
+            state.checkpoint();
+        }
+        result
+    }
+
+    /// Ensures the next change starts a new step, even if it falls within the coalescing window.
+    pub fn checkpoint(&self) {
+        self.state.borrow_mut().checkpoint();
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.state.borrow().undo.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.state.borrow().redo.is_empty()
+    }
+
+    /// Reverts the most recent step. Returns false if there was nothing to undo.
+    pub fn undo(&self) -> bool {
+        let changes = match self.state.borrow_mut().undo.pop() {
+            Some(changes) => changes,
+            None => return false,
+        };
+        self.apply(changes.iter().rev().map(|change| &change.undo));
+        let mut state = self.state.borrow_mut();
+        state.redo.push(changes);
+        state.checkpoint();
+        true
+    }
+
+    /// Reapplies the most recently undone step. Returns false if there was nothing to redo.
+    pub fn redo(&self) -> bool {
+        let changes = match self.state.borrow_mut().redo.pop() {
+            Some(changes) => changes,
+            None => return false,
+        };
+        self.apply(changes.iter().map(|change| &change.redo));
+        let mut state = self.state.borrow_mut();
+        state.undo.push(changes);
+        state.checkpoint();
+        true
+    }
+
+    /// Forgets all recorded steps.
+    pub fn clear(&self) {
+        let mut state = self.state.borrow_mut();
+        state.undo.clear();
+        state.redo.clear();
+        state.checkpoint();
+    }
+
+    fn apply<'a, I: Iterator<Item = &'a Rc<dyn Fn()>>>(&self, actions: I) {
+        // The state can't be borrowed while applying, as updating a storage notifies our subscription
+        self.state.borrow_mut().applying = true;
+        for action in actions {
+            action();
+        }
+        self.state.borrow_mut().applying = false;
+    }
+}
+
+impl HistoryState {
+    fn record(&mut self, change: Change) {
+        if let Some(transaction) = &mut self.transaction {
+            transaction.push(change);
+            return;
+        }
+
+        let now = (self.clock)();
+        let coalesce = self.last_storage == Some(change.storage)
+            && self
+                .last_change
+                .map(|last_change| now - last_change <= self.coalesce_window)
+                .unwrap_or_default();
+        self.last_storage = Some(change.storage);
+        self.last_change = Some(now);
+
+        match self.undo.last_mut() {
+            // Keep the original undo action, so that undoing restores the value from before the burst of changes
+            Some(step) if coalesce && step.len() == 1 => {
+                step[0].redo = change.redo;
+                self.redo.clear();
+            }
+            _ => self.push(vec![change]),
+        }
+    }
+
+    fn push(&mut self, changes: Vec<Change>) {
+        self.undo.push(changes);
+        self.redo.clear();
+    }
+
+    fn checkpoint(&mut self) {
+        self.last_storage = None;
+        self.last_change = None;
+    }
+}
+
+/// A `FormStorage` with its own undo/redo history.
+#[derive(Clone, Debug)]
+pub struct HistoryStorage<T>
+where
+    T: std::fmt::Debug + Default + Clone + PartialEq + 'static,
+{
+    storage: FormStorage<T>,
+    history: FormHistory,
+}
+
+impl<T> HistoryStorage<T>
+where
+    T: std::fmt::Debug + Default + Clone + PartialEq + 'static,
+{
+    pub fn new(value: T) -> Self {
+        Self::with_history(FormStorage::new(value), FormHistory::default())
+    }
+
+    /// Records changes made to `storage` in `history`.
+    pub fn with_history(storage: FormStorage<T>, history: FormHistory) -> Self {
+        history.track(&storage);
+        Self { storage, history }
+    }
+
+    /// The underlying storage, to be passed to form components.
+    pub fn storage(&self) -> &FormStorage<T> {
+        &self.storage
+    }
+
+    pub fn history(&self) -> &FormHistory {
+        &self.history
+    }
+
+    pub fn can_undo(&self) -> bool {
+        self.history.can_undo()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        self.history.can_redo()
+    }
+
+    pub fn undo(&self) -> bool {
+        self.history.undo()
+    }
+
+    pub fn redo(&self) -> bool {
+        self.history.redo()
+    }
+
+    pub fn checkpoint(&self) {
+        self.history.checkpoint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    /// A clock that advances manually, converting from JavaScript timestamps like the default clock does.
+    fn manual_clock() -> (Rc<Cell<i64>>, impl Fn() -> DateTime<Utc>) {
+        const START: f64 = 1_600_000_000_000.;
+        let millis = Rc::new(Cell::new(0));
+        let clock = {
+            let millis = millis.clone();
+            move || crate::utc_from_js_timestamp(START + millis.get() as f64)
+        };
+        (millis, clock)
+    }
+
+    #[test]
+    fn default_clock_conversion() {
+        let (millis, clock) = manual_clock();
+        let first = clock();
+        assert_eq!(first.timestamp(), 1_600_000_000);
+        millis.set(200);
+        assert_eq!(
+            (clock() - first).to_std().unwrap(),
+            Duration::from_millis(200)
+        );
+    }
+
+    #[test]
+    fn coalescing() {
+        let (millis, clock) = manual_clock();
+        let history = FormHistory::default().with_clock(clock);
+        let storage = HistoryStorage::with_history(FormStorage::new(String::new()), history);
+        let mut input = storage.storage().clone();
+        assert!(!storage.can_undo());
+
+        input.update("h".to_owned());
+        millis.set(200);
+        input.update("hi".to_owned());
+        millis.set(5_000);
+        input.update("hi there".to_owned());
+
+        assert!(storage.undo());
+        assert_eq!(input.unchecked_value(), "hi");
+        assert!(storage.undo());
+        assert_eq!(input.unchecked_value(), "");
+        assert!(!storage.undo());
+
+        assert!(storage.redo());
+        assert_eq!(input.unchecked_value(), "hi");
+        assert!(storage.redo());
+        assert_eq!(input.unchecked_value(), "hi there");
+        assert!(!storage.can_redo());
+
+        // A new change discards the redo history
+        storage.undo();
+        input.update("hey".to_owned());
+        assert!(!storage.can_redo());
+        storage.undo();
+        assert_eq!(input.unchecked_value(), "hi");
+    }
+
+    #[test]
+    fn checkpoints() {
+        let (_, clock) = manual_clock();
+        let storage = HistoryStorage::with_history(
+            FormStorage::new(0),
+            FormHistory::default().with_clock(clock),
+        );
+        let mut input = storage.storage().clone();
+        input.update(1);
+        storage.checkpoint();
+        input.update(2);
+        storage.undo();
+        assert_eq!(input.unchecked_value(), 1);
+    }
+
+    #[test]
+    fn transactions() {
+        let (_, clock) = manual_clock();
+        let history = FormHistory::default().with_clock(clock);
+        let mut first = FormStorage::new(String::new());
+        let mut second = FormStorage::new(0);
+        history.track(&first);
+        history.track(&second);
+
+        first.update("a".to_owned());
+        history.transaction(|| {
+            first.update("b".to_owned());
+            second.update(2);
+        });
+        second.update(3);
+
+        assert!(history.undo());
+        assert_eq!(second.unchecked_value(), 2);
+        assert!(history.undo());
+        assert_eq!(first.unchecked_value(), "a");
+        assert_eq!(second.unchecked_value(), 0);
+        assert!(history.undo());
+        assert_eq!(first.unchecked_value(), "");
+        assert!(!history.can_undo());
+
+        assert!(history.redo());
+        assert!(history.redo());
+        assert_eq!(first.unchecked_value(), "b");
+        assert_eq!(second.unchecked_value(), 2);
+    }
+}
@@ -0,0 +1,225 @@
+use std::{collections::HashMap, rc::Rc};
+
+use yew::prelude::*;
+
+use crate::forms::FormField;
+
+use super::{
+    storage::FormStorage,
+    text_input::{InputType, TextInput},
+};
+
+/// Scores how hard a password is to guess, used to drive the strength meter of `PasswordInput`.
+pub trait PasswordStrength {
+    /// Returns a score between 0.0 (trivial) and 1.0 (strong).
+    fn score(&self, password: &str) -> f32;
+}
+
+/// A simple `PasswordStrength` that rewards length and a mix of lowercase, uppercase, digits and symbols.
+#[derive(Debug, Clone)]
+pub struct BasicPasswordStrength {
+    pub target_length: usize,
+}
+
+impl Default for BasicPasswordStrength {
+    fn default() -> Self {
+        Self { target_length: 12 }
+    }
+}
+
+impl PasswordStrength for BasicPasswordStrength {
+    fn score(&self, password: &str) -> f32 {
+        let length = password.chars().count();
+        if length == 0 {
+            return 0.;
+        }
+
+        let has_lowercase = password.chars().any(|c| c.is_lowercase());
+        let has_uppercase = password.chars().any(|c| c.is_uppercase());
+        let has_digit = password.chars().any(|c| c.is_numeric());
+        let has_symbol = password.chars().any(|c| !c.is_alphanumeric());
+        let variety = [has_lowercase, has_uppercase, has_digit, has_symbol]
+            .iter()
+            .filter(|present| **present)
+            .count();
+
+        let length_score = (length as f32 / self.target_length.max(1) as f32).min(1.);
+        let variety_score = variety as f32 / 4.;
+        length_score * 0.5 + variety_score * 0.5
+    }
+}
+
+pub struct PasswordInput<T>
+where
+    T: FormField,
+{
+    props: Props<T>,
+    revealed: bool,
+    score: Option<f32>,
+    link: ComponentLink<Self>,
+}
+
+#[derive(Clone, Properties)]
+pub struct Props<T>
+where
+    T: FormField,
+{
+    #[prop_or_default]
+    pub on_value_changed: Callback<Option<String>>,
+    pub storage: FormStorage<Option<String>>,
+    pub field: T,
+    pub errors: Option<Rc<HashMap<T, Vec<Rc<Html>>>>>,
+    #[prop_or_default]
+    pub placeholder: String,
+    #[prop_or_default]
+    pub disabled: bool,
+    #[prop_or_default]
+    pub readonly: bool,
+    #[prop_or_default]
+    pub autofocus: bool,
+    #[prop_or_default]
+    pub name: Option<String>,
+    /// Typically `current-password` for login forms or `new-password` for registration forms.
+    #[prop_or_default]
+    pub autocomplete: Option<String>,
+    /// When set, a strength meter is rendered below the input.
+    #[prop_or_default]
+    pub strength: Option<Rc<dyn PasswordStrength>>,
+}
+
+pub enum Message {
+    ToggleRevealed,
+    ValueChanged(Option<String>),
+}
+
+impl<T> Component for PasswordInput<T>
+where
+    T: FormField,
+{
+    type Message = Message;
+    type Properties = Props<T>;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let score = Self::score_for(&props);
+        Self {
+            props,
+            revealed: false,
+            score,
+            link,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Message::ToggleRevealed => {
+                self.revealed = !self.revealed;
+                true
+            }
+            Message::ValueChanged(value) => {
+                self.props.on_value_changed.emit(value);
+                let score = Self::score_for(&self.props);
+                if score != self.score {
+                    self.score = score;
+                    true
+                } else {
+                    false
+                }
+            }
+        }
+    }
+
+    fn view(&self) -> Html {
+        let input_type = if self.revealed {
+            InputType::Text
+        } else {
+            InputType::Password
+        };
+        let (icon, toggle_label) = if self.revealed {
+            ("fas fa-eye-slash", "Hide password")
+        } else {
+            ("fas fa-eye", "Show password")
+        };
+        html! {
+            <>
+                <div class="field has-addons">
+                    <TextInput<T, String>
+                        field=self.props.field
+                        storage=self.props.storage.clone()
+                        errors=self.props.errors.clone()
+                        on_value_changed=self.link.callback(Message::ValueChanged)
+                        input_type=input_type
+                        placeholder=&self.props.placeholder
+                        name=self.props.name.clone()
+                        autocomplete=self.props.autocomplete.clone()
+                        disabled=self.props.disabled
+                        readonly=self.props.readonly
+                        autofocus=self.props.autofocus />
+                    <div class="control">
+                        <button
+                            class="button"
+                            type="button"
+                            aria-label=toggle_label
+                            title=toggle_label
+                            disabled=self.props.disabled
+                            onclick=self.link.callback(|_| Message::ToggleRevealed)>
+                            <span class="icon"><i class=icon /></span>
+                        </button>
+                    </div>
+                </div>
+                { self.strength_meter() }
+            </>
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        self.score = Self::score_for(&props);
+        self.props = props;
+        true
+    }
+}
+
+impl<T> PasswordInput<T>
+where
+    T: FormField,
+{
+    fn score_for(props: &Props<T>) -> Option<f32> {
+        props.strength.as_ref().map(|strength| {
+            strength
+                .score(&props.storage.unchecked_value().unwrap_or_default())
+                .clamp(0., 1.)
+        })
+    }
+
+    fn strength_meter(&self) -> Html {
+        match self.score {
+            Some(score) => {
+                let css_class = if score < 0.34 {
+                    "progress is-small is-danger"
+                } else if score < 0.67 {
+                    "progress is-small is-warning"
+                } else {
+                    "progress is-small is-success"
+                };
+                let value = ((score * 100.) as u32).to_string();
+                html! {
+                    <progress class=css_class value=value max="100">{ format!("{}%", value) }</progress>
+                }
+            }
+            None => Html::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn basic_strength() {
+        let strength = BasicPasswordStrength::default();
+        assert!(strength.score("").abs() < f32::EPSILON);
+        assert!(strength.score("password") < strength.score("Password1"));
+        assert!(strength.score("Password1") < strength.score("C0rrect-Horse-Battery"));
+        assert!((strength.score("C0rrect-Horse-Battery") - 1.).abs() < f32::EPSILON);
+    }
+}
@@ -0,0 +1,244 @@
+use std::{cell::RefCell, rc::Rc, time::Duration};
+
+use chrono::{DateTime, Utc};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use yew::{
+    services::timeout::{TimeoutService, TimeoutTask},
+    Callback,
+};
+
+use super::storage::{FormStorage, SubscriptionHandle};
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum StorageArea {
+    /// Drafts survive the browser being closed.
+    Local,
+    /// Drafts are discarded when the tab is closed.
+    Session,
+}
+
+impl StorageArea {
+    fn storage(self) -> Option<web_sys::Storage> {
+        let window = web_sys::window()?;
+        let storage = match self {
+            StorageArea::Local => window.local_storage(),
+            StorageArea::Session => window.session_storage(),
+        };
+        // Storage can be unavailable, such as in some private browsing modes, in which case drafts are not kept
+        storage.ok().flatten()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PersistenceOptions {
+    pub area: StorageArea,
+    /// How long a draft is kept after it was last written.
+    pub expires_after: chrono::Duration,
+    /// How long to wait after the last change before writing the draft.
+    pub debounce: Duration,
+}
+
+impl Default for PersistenceOptions {
+    fn default() -> Self {
+        Self {
+            area: StorageArea::Local,
+            expires_after: chrono::Duration::days(7),
+            debounce: Duration::from_millis(500),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct Draft<T> {
+    expires_at: DateTime<Utc>,
+    value: T,
+}
+
+/// Mirrors a `FormStorage` into the browser's `localStorage` or `sessionStorage`, so that a draft
+/// survives the page being reloaded.
+///
+/// An unexpired draft is restored into the storage on creation, leaving the storage dirty. Drafts
+/// are written once the value stops changing for the configured debounce period.
+pub struct PersistentStorage<T>
+where
+    T: std::fmt::Debug + Default + Clone + PartialEq + Serialize + DeserializeOwned + 'static,
+{
+    storage: FormStorage<T>,
+    key: String,
+    area: StorageArea,
+    restored: bool,
+    pending_write: Rc<RefCell<Option<TimeoutTask>>>,
+    _subscription: SubscriptionHandle,
+}
+
+impl<T> PersistentStorage<T>
+where
+    T: std::fmt::Debug + Default + Clone + PartialEq + Serialize + DeserializeOwned + 'static,
+{
+    pub fn new(storage: FormStorage<T>, namespace: &str, key: &str) -> Self {
+        Self::with_options(storage, namespace, key, PersistenceOptions::default())
+    }
+
+    pub fn with_options(
+        mut storage: FormStorage<T>,
+        namespace: &str,
+        key: &str,
+        options: PersistenceOptions,
+    ) -> Self {
+        let key = draft_key(namespace, key);
+        let area = options.area;
+
+        let draft = area
+            .storage()
+            .and_then(|web_storage| web_storage.get_item(&key).ok().flatten());
+        let restored = match draft.and_then(|draft| decode_draft(&draft, crate::wasm_utc_now())) {
+            Some(value) => {
+                storage.update(value);
+                true
+            }
+            None => {
+                remove_draft(area, &key);
+                false
+            }
+        };
+
+        let pending_write = Rc::new(RefCell::new(None));
+        let subscription = {
+            let key = key.clone();
+            let pending_write = pending_write.clone();
+            let PersistenceOptions {
+                expires_after,
+                debounce,
+                ..
+            } = options;
+            storage.subscribe(Callback::from(move |value: T| {
+                let key = key.clone();
+                // Replacing the task cancels the previously scheduled write
+                *pending_write.borrow_mut() = Some(TimeoutService::spawn(
+                    debounce,
+                    Callback::from(move |_| {
+                        write_draft(area, &key, &value, crate::wasm_utc_now() + expires_after)
+                    }),
+                ));
+            }))
+        };
+
+        Self {
+            storage,
+            key,
+            area,
+            restored,
+            pending_write,
+            _subscription: subscription,
+        }
+    }
+
+    pub fn storage(&self) -> &FormStorage<T> {
+        &self.storage
+    }
+
+    /// Returns true if a draft was restored into the storage on creation.
+    pub fn was_restored(&self) -> bool {
+        self.restored
+    }
+
+    /// Removes the saved draft, such as after the form has been submitted. The storage's value is left untouched.
+    pub fn discard_draft(&mut self) {
+        self.pending_write.borrow_mut().take();
+        remove_draft(self.area, &self.key);
+        self.restored = false;
+    }
+}
+
+impl<T> std::fmt::Debug for PersistentStorage<T>
+where
+    T: std::fmt::Debug + Default + Clone + PartialEq + Serialize + DeserializeOwned + 'static,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PersistentStorage")
+            .field("storage", &self.storage)
+            .field("key", &self.key)
+            .field("area", &self.area)
+            .field("restored", &self.restored)
+            .finish()
+    }
+}
+
+fn draft_key(namespace: &str, key: &str) -> String {
+    format!("yew-bulma.drafts.{}.{}", namespace, key)
+}
+
+fn decode_draft<T: DeserializeOwned>(draft: &str, now: DateTime<Utc>) -> Option<T> {
+    let draft = serde_json::from_str::<Draft<T>>(draft).ok()?;
+    if draft.expires_at > now {
+        Some(draft.value)
+    } else {
+        None
+    }
+}
+
+fn write_draft<T: Serialize>(area: StorageArea, key: &str, value: &T, expires_at: DateTime<Utc>) {
+    if let (Some(web_storage), Ok(draft)) = (
+        area.storage(),
+        serde_json::to_string(&Draft { expires_at, value }),
+    ) {
+        let _ = web_storage.set_item(key, &draft);
+    }
+}
+
+fn remove_draft(area: StorageArea, key: &str) {
+    if let Some(web_storage) = area.storage() {
+        let _ = web_storage.remove_item(key);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn drafts() {
+        assert_eq!(
+            draft_key("signup", "email"),
+            "yew-bulma.drafts.signup.email"
+        );
+
+        let now = DateTime::from_timestamp(1_000_000, 0).unwrap();
+        let draft = serde_json::to_string(&Draft {
+            expires_at: now + chrono::Duration::minutes(1),
+            value: "hello",
+        })
+        .unwrap();
+        assert_eq!(
+            decode_draft::<String>(&draft, now),
+            Some("hello".to_owned())
+        );
+        assert_eq!(
+            decode_draft::<String>(&draft, now + chrono::Duration::minutes(2)),
+            None
+        );
+        // Drafts that no longer match the value's type are ignored
+        assert_eq!(decode_draft::<u32>(&draft, now), None);
+        assert_eq!(decode_draft::<String>("not json", now), None);
+    }
+
+    #[test]
+    fn default_expiry() {
+        // JavaScript timestamps are in milliseconds, as returned by `Date.now()`
+        const WRITTEN_AT: f64 = 1_600_000_000_000.;
+        const DAY: f64 = 24. * 60. * 60. * 1000.;
+        let expires_at =
+            crate::utc_from_js_timestamp(WRITTEN_AT) + PersistenceOptions::default().expires_after;
+        let draft = serde_json::to_string(&Draft {
+            expires_at,
+            value: "hello",
+        })
+        .unwrap();
+        let decode_at =
+            |millis: f64| decode_draft::<String>(&draft, crate::utc_from_js_timestamp(millis));
+
+        assert!(decode_at(WRITTEN_AT + 10. * 60. * 1000.).is_some());
+        assert!(decode_at(WRITTEN_AT + 6. * DAY).is_some());
+        assert!(decode_at(WRITTEN_AT + 8. * DAY).is_none());
+    }
+}
@@ -36,6 +36,7 @@ where
     V: Default + std::fmt::Debug + Copy + Eq + 'static,
 {
     RadioSelected(V),
+    Blurred,
 }
 
 impl<T, V> Component for Radio<T, V>
@@ -52,6 +53,7 @@ where
 
     fn update(&mut self, msg: Self::Message) -> ShouldRender {
         match msg {
+            Message::Blurred => self.props.storage.touch(),
             Message::RadioSelected(value) => {
                 self.props.storage.update(value);
                 self.props.on_value_changed.emit(value);
@@ -61,17 +63,16 @@ where
     }
 
     fn view(&self) -> Html {
-        let errors = self
+        let has_errors = self
             .props
             .errors
             .as_ref()
-            .map(|errors| errors.get(&self.props.field).cloned());
-        let css_class = match &errors {
-            Some(errors) => match errors {
-                Some(_) => "control is-danger",
-                None => "control",
-            },
-            None => "control",
+            .map(|errors| errors.contains_key(&self.props.field))
+            .unwrap_or_default();
+        let css_class = if has_errors {
+            "control is-danger"
+        } else {
+            "control"
         };
         html! {
             <div class=css_class>
@@ -94,7 +95,7 @@ where
     fn render_option(&self, label: &str, value: V) -> Html {
         html! {
             <label class="radio">
-                <input type="radio" onclick=self.link.callback(move |_| Message::RadioSelected(value)) checked=self.props.storage.unchecked_value() == value disabled=self.props.disabled />
+                <input type="radio" onclick=self.link.callback(move |_| Message::RadioSelected(value)) onblur=self.link.callback(|_| Message::Blurred) checked=self.props.storage.unchecked_value() == value disabled=self.props.disabled />
                 { label }
             </label>
         }
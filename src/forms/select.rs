@@ -0,0 +1,241 @@
+use super::storage::FormStorage;
+use crate::forms::FormField;
+use std::collections::HashMap;
+use std::rc::Rc;
+use web_sys::HtmlSelectElement;
+use yew::prelude::*;
+
+pub struct Select<T, V>
+where
+    T: FormField,
+    V: Default + std::fmt::Debug + Copy + Eq + 'static,
+{
+    props: Props<T, V>,
+    select: NodeRef,
+    link: ComponentLink<Self>,
+}
+
+#[derive(Clone, Properties)]
+pub struct Props<T, V>
+where
+    T: FormField,
+    V: Default + std::fmt::Debug + Copy + Eq + 'static,
+{
+    #[prop_or_default]
+    pub on_value_changed: Callback<Option<V>>,
+    pub storage: FormStorage<Option<V>>,
+    pub field: T,
+    pub errors: Option<Rc<HashMap<T, Vec<Rc<Html>>>>>,
+    pub options: Vec<(String, V)>,
+    /// When not empty, an option with this label is rendered first and selecting it clears the value.
+    #[prop_or_default]
+    pub placeholder: String,
+    #[prop_or_default]
+    pub disabled: bool,
+    /// Additional classes for the `select` wrapper, such as `is-small` or `is-primary`.
+    #[prop_or_default]
+    pub css_class: String,
+}
+
+pub enum Message {
+    SelectionChanged,
+    Blurred,
+}
+
+impl<T, V> Component for Select<T, V>
+where
+    T: FormField,
+    V: Default + std::fmt::Debug + Copy + Eq + 'static,
+{
+    type Message = Message;
+    type Properties = Props<T, V>;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            props,
+            link,
+            select: NodeRef::default(),
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Message::Blurred => self.props.storage.touch(),
+            Message::SelectionChanged => {
+                if let Some(select) = self.select.cast::<HtmlSelectElement>() {
+                    let value = select
+                        .value()
+                        .parse::<usize>()
+                        .ok()
+                        .and_then(|index| self.props.options.get(index))
+                        .map(|(_, value)| *value);
+                    self.props.storage.update(value);
+                    self.props.on_value_changed.emit(value);
+                }
+            }
+        }
+        false
+    }
+
+    fn view(&self) -> Html {
+        let css_class = select_css_class(
+            self.props.errors.as_deref(),
+            &self.props.field,
+            &self.props.css_class,
+        );
+        let current_value = self.props.storage.unchecked_value();
+        let placeholder = if self.props.placeholder.is_empty() {
+            Html::default()
+        } else {
+            html! {
+                <option value="" selected=current_value.is_none()>{ &self.props.placeholder }</option>
+            }
+        };
+        html! {
+            <div class="control">
+                <div class=css_class>
+                    <select
+                        id=self.props.field.form_id()
+                        ref=self.select.clone()
+                        onchange=self.link.callback(|_| Message::SelectionChanged)
+                        onblur=self.link.callback(|_| Message::Blurred)
+                        disabled=self.props.disabled>
+                        { placeholder }
+                        { self.props.options.iter().enumerate().map(|(index, (label, value))| render_option(index, label, current_value == Some(*value))).collect::<Html>() }
+                    </select>
+                </div>
+            </div>
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        self.props = props;
+        true
+    }
+}
+
+pub struct MultiSelect<T, V>
+where
+    T: FormField,
+    V: Default + std::fmt::Debug + Copy + Eq + 'static,
+{
+    props: MultiSelectProps<T, V>,
+    select: NodeRef,
+    link: ComponentLink<Self>,
+}
+
+#[derive(Clone, Properties)]
+pub struct MultiSelectProps<T, V>
+where
+    T: FormField,
+    V: Default + std::fmt::Debug + Copy + Eq + 'static,
+{
+    #[prop_or_default]
+    pub on_value_changed: Callback<Vec<V>>,
+    pub storage: FormStorage<Vec<V>>,
+    pub field: T,
+    pub errors: Option<Rc<HashMap<T, Vec<Rc<Html>>>>>,
+    pub options: Vec<(String, V)>,
+    /// The number of visible rows.
+    #[prop_or_default]
+    pub size: Option<u32>,
+    #[prop_or_default]
+    pub disabled: bool,
+    /// Additional classes for the `select` wrapper, such as `is-small` or `is-primary`.
+    #[prop_or_default]
+    pub css_class: String,
+}
+
+impl<T, V> Component for MultiSelect<T, V>
+where
+    T: FormField,
+    V: Default + std::fmt::Debug + Copy + Eq + 'static,
+{
+    type Message = Message;
+    type Properties = MultiSelectProps<T, V>;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            props,
+            link,
+            select: NodeRef::default(),
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Message::Blurred => self.props.storage.touch(),
+            Message::SelectionChanged => {
+                if let Some(select) = self.select.cast::<HtmlSelectElement>() {
+                    let selected = select.selected_options();
+                    let values = (0..selected.length())
+                        .filter_map(|index| selected.item(index))
+                        .filter_map(|option| option.get_attribute("value"))
+                        .filter_map(|index| index.parse::<usize>().ok())
+                        .filter_map(|index| self.props.options.get(index))
+                        .map(|(_, value)| *value)
+                        .collect::<Vec<_>>();
+                    self.props.storage.update(values.clone());
+                    self.props.on_value_changed.emit(values);
+                }
+            }
+        }
+        false
+    }
+
+    fn view(&self) -> Html {
+        let css_class = select_css_class(
+            self.props.errors.as_deref(),
+            &self.props.field,
+            &format!("is-multiple {}", self.props.css_class),
+        );
+        let current_values = self.props.storage.unchecked_value();
+        let size = self
+            .props
+            .size
+            .map(|size| size.to_string())
+            .unwrap_or_default();
+        html! {
+            <div class="control">
+                <div class=css_class>
+                    <select
+                        id=self.props.field.form_id()
+                        ref=self.select.clone()
+                        multiple=true
+                        size=size
+                        onchange=self.link.callback(|_| Message::SelectionChanged)
+                        onblur=self.link.callback(|_| Message::Blurred)
+                        disabled=self.props.disabled>
+                        { self.props.options.iter().enumerate().map(|(index, (label, value))| render_option(index, label, current_values.contains(value))).collect::<Html>() }
+                    </select>
+                </div>
+            </div>
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        self.props = props;
+        true
+    }
+}
+
+fn select_css_class<T: FormField>(
+    errors: Option<&HashMap<T, Vec<Rc<Html>>>>,
+    field: &T,
+    css_class: &str,
+) -> String {
+    let has_errors = errors
+        .map(|errors| errors.contains_key(field))
+        .unwrap_or_default();
+    if has_errors {
+        format!("select {} is-danger", css_class)
+    } else {
+        format!("select {}", css_class)
+    }
+}
+
+fn render_option(index: usize, label: &str, selected: bool) -> Html {
+    html! {
+        <option value=index.to_string() selected=selected>{ label }</option>
+    }
+}
@@ -0,0 +1,124 @@
+use std::{collections::HashMap, rc::Rc, str::FromStr};
+
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+use crate::forms::FormField;
+
+use super::storage::FormStorage;
+
+/// A range input styled with the [bulma-slider](https://wikiki.github.io/form/slider/) extension.
+pub struct Slider<T, V>
+where
+    T: FormField,
+    V: Clone + Default + FromStr + ToString + std::fmt::Debug + PartialEq + 'static,
+{
+    props: Props<T, V>,
+    input: NodeRef,
+    link: ComponentLink<Self>,
+}
+
+#[derive(Clone, Properties)]
+pub struct Props<T, V>
+where
+    T: FormField,
+    V: Clone + Default + FromStr + ToString + std::fmt::Debug + PartialEq + 'static,
+{
+    #[prop_or_default]
+    pub on_value_changed: Callback<V>,
+    pub storage: FormStorage<V>,
+    pub field: T,
+    pub errors: Option<Rc<HashMap<T, Vec<Rc<Html>>>>>,
+    pub min: V,
+    pub max: V,
+    pub step: V,
+    #[prop_or_default]
+    pub disabled: bool,
+    /// Additional classes for the slider, such as `is-circle` or `is-primary`.
+    #[prop_or_default]
+    pub css_class: String,
+}
+
+pub enum Message {
+    ValueChanged,
+    Blurred,
+}
+
+impl<T, V> Component for Slider<T, V>
+where
+    T: FormField,
+    V: Clone + Default + FromStr + ToString + std::fmt::Debug + PartialEq + 'static,
+{
+    type Message = Message;
+    type Properties = Props<T, V>;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            props,
+            link,
+            input: NodeRef::default(),
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Message::Blurred => self.props.storage.touch(),
+            Message::ValueChanged => {
+                if let Some(input) = self.input.cast::<HtmlInputElement>() {
+                    match V::from_str(&input.value()) {
+                        Ok(value) => {
+                            self.props
+                                .storage
+                                .update_with_invalid_hint(value.clone(), false);
+                            self.props.on_value_changed.emit(value);
+                        }
+                        Err(_) => self.props.storage.update_invalid_hint(true),
+                    }
+                    return true;
+                }
+            }
+        }
+        false
+    }
+
+    fn view(&self) -> Html {
+        let has_errors = self
+            .props
+            .errors
+            .as_ref()
+            .map(|errors| errors.contains_key(&self.props.field))
+            .unwrap_or_default();
+        let css_class = if has_errors {
+            format!(
+                "slider is-fullwidth has-output {} is-danger",
+                self.props.css_class
+            )
+        } else {
+            format!("slider is-fullwidth has-output {}", self.props.css_class)
+        };
+        let value = self.props.storage.unchecked_value().to_string();
+        html! {
+            <div class="control">
+                <input
+                    id=self.props.field.form_id()
+                    class=css_class
+                    ref=self.input.clone()
+                    type="range"
+                    min=self.props.min.to_string()
+                    max=self.props.max.to_string()
+                    step=self.props.step.to_string()
+                    value=&value
+                    oninput=self.link.callback(|_| Message::ValueChanged)
+                    onchange=self.link.callback(|_| Message::ValueChanged)
+                    onblur=self.link.callback(|_| Message::Blurred)
+                    disabled=self.props.disabled />
+                <output for=self.props.field.form_id()>{ value }</output>
+            </div>
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        self.props = props;
+        true
+    }
+}
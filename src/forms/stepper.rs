@@ -0,0 +1,283 @@
+use std::{collections::HashMap, rc::Rc, str::FromStr};
+
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+use crate::forms::FormField;
+
+use super::storage::FormStorage;
+
+/// A numeric value that can be incremented and decremented by a `Stepper`.
+pub trait Steppable:
+    Copy + Default + PartialOrd + FromStr + ToString + std::fmt::Debug + 'static
+{
+    fn one() -> Self;
+    fn step_up(self, step: Self) -> Self;
+    fn step_down(self, step: Self) -> Self;
+}
+
+macro_rules! impl_steppable_int {
+    ($($t:ty),*) => {
+        $(
+            impl Steppable for $t {
+                fn one() -> Self {
+                    1
+                }
+
+                fn step_up(self, step: Self) -> Self {
+                    self.saturating_add(step)
+                }
+
+                fn step_down(self, step: Self) -> Self {
+                    self.saturating_sub(step)
+                }
+            }
+        )*
+    };
+}
+
+impl_steppable_int!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl Steppable for f32 {
+    fn one() -> Self {
+        1.
+    }
+
+    fn step_up(self, step: Self) -> Self {
+        self + step
+    }
+
+    fn step_down(self, step: Self) -> Self {
+        self - step
+    }
+}
+
+impl Steppable for f64 {
+    fn one() -> Self {
+        1.
+    }
+
+    fn step_up(self, step: Self) -> Self {
+        self + step
+    }
+
+    fn step_down(self, step: Self) -> Self {
+        self - step
+    }
+}
+
+fn clamp<V: PartialOrd>(value: V, min: Option<V>, max: Option<V>) -> V {
+    match (min, max) {
+        (Some(min), _) if value < min => min,
+        (_, Some(max)) if value > max => max,
+        _ => value,
+    }
+}
+
+/// A number field surrounded by -/+ buttons.
+pub struct Stepper<T, V>
+where
+    T: FormField,
+    V: Steppable,
+{
+    props: Props<T, V>,
+    text_value: String,
+    input: NodeRef,
+    link: ComponentLink<Self>,
+}
+
+#[derive(Clone, Properties)]
+pub struct Props<T, V>
+where
+    T: FormField,
+    V: Steppable,
+{
+    #[prop_or_default]
+    pub on_value_changed: Callback<V>,
+    pub storage: FormStorage<V>,
+    pub field: T,
+    pub errors: Option<Rc<HashMap<T, Vec<Rc<Html>>>>>,
+    #[prop_or_default]
+    pub min: Option<V>,
+    #[prop_or_default]
+    pub max: Option<V>,
+    /// Defaults to 1
+    #[prop_or_default]
+    pub step: Option<V>,
+    #[prop_or_default]
+    pub disabled: bool,
+}
+
+pub enum Message {
+    Decrement,
+    Increment,
+    KeyPressed,
+    Changed,
+    Blurred,
+}
+
+impl<T, V> Component for Stepper<T, V>
+where
+    T: FormField,
+    V: Steppable,
+{
+    type Message = Message;
+    type Properties = Props<T, V>;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let text_value = props.storage.unchecked_value().to_string();
+        Self {
+            props,
+            text_value,
+            link,
+            input: NodeRef::default(),
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        let step = self.props.step.unwrap_or_else(V::one);
+        match msg {
+            Message::Blurred => {
+                self.props.storage.touch();
+                false
+            }
+            Message::Decrement => {
+                let value = self.props.storage.unchecked_value().step_down(step);
+                self.set_value(value);
+                true
+            }
+            Message::Increment => {
+                let value = self.props.storage.unchecked_value().step_up(step);
+                self.set_value(value);
+                true
+            }
+            Message::KeyPressed => {
+                // Out of range values are kept while typing, such as the 1 of 15 when the minimum is 10
+                let limits = self.limits_reached();
+                if let Some(input) = self.input.cast::<HtmlInputElement>() {
+                    self.text_value = input.value();
+                    match V::from_str(&self.text_value) {
+                        Ok(value) => {
+                            self.props.storage.update_with_invalid_hint(value, false);
+                            self.props.on_value_changed.emit(value);
+                        }
+                        Err(_) => self.props.storage.update_invalid_hint(true),
+                    }
+                }
+                limits != self.limits_reached()
+            }
+            Message::Changed => {
+                // The value is committed, so it is clamped to the range
+                match self
+                    .input
+                    .cast::<HtmlInputElement>()
+                    .and_then(|input| V::from_str(&input.value()).ok())
+                {
+                    Some(value) => {
+                        self.set_value(value);
+                        true
+                    }
+                    None => false,
+                }
+            }
+        }
+    }
+
+    fn view(&self) -> Html {
+        let has_errors = self
+            .props
+            .errors
+            .as_ref()
+            .map(|errors| errors.contains_key(&self.props.field))
+            .unwrap_or_default();
+        let css_class = if has_errors {
+            "input is-danger"
+        } else {
+            "input"
+        };
+        let (at_min, at_max) = self.limits_reached();
+        html! {
+            <div class="field has-addons">
+                <div class="control">
+                    <button
+                        class="button"
+                        type="button"
+                        aria-label="decrement"
+                        disabled=self.props.disabled || at_min
+                        onclick=self.link.callback(|_| Message::Decrement)>
+                        { "−" }
+                    </button>
+                </div>
+                <div class="control">
+                    <input
+                        id=self.props.field.form_id()
+                        class=css_class
+                        ref=self.input.clone()
+                        type="number"
+                        value=&self.text_value
+                        min=self.props.min.map(|min| min.to_string()).unwrap_or_default()
+                        max=self.props.max.map(|max| max.to_string()).unwrap_or_default()
+                        step=self.props.step.map(|step| step.to_string()).unwrap_or_default()
+                        oninput=self.link.callback(|_| Message::KeyPressed)
+                        onchange=self.link.callback(|_| Message::Changed)
+                        onblur=self.link.callback(|_| Message::Blurred)
+                        disabled=self.props.disabled />
+                </div>
+                <div class="control">
+                    <button
+                        class="button"
+                        type="button"
+                        aria-label="increment"
+                        disabled=self.props.disabled || at_max
+                        onclick=self.link.callback(|_| Message::Increment)>
+                        { "+" }
+                    </button>
+                </div>
+            </div>
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        self.text_value = props.storage.unchecked_value().to_string();
+        self.props = props;
+        true
+    }
+}
+
+impl<T, V> Stepper<T, V>
+where
+    T: FormField,
+    V: Steppable,
+{
+    /// Whether the value is at the minimum and maximum, disabling the -/+ buttons.
+    fn limits_reached(&self) -> (bool, bool) {
+        let value = self.props.storage.unchecked_value();
+        (
+            self.props.min.map(|min| value <= min).unwrap_or_default(),
+            self.props.max.map(|max| value >= max).unwrap_or_default(),
+        )
+    }
+
+    fn set_value(&mut self, value: V) {
+        let value = clamp(value, self.props.min, self.props.max);
+        self.text_value = value.to_string();
+        self.props.storage.update_with_invalid_hint(value, false);
+        self.props.on_value_changed.emit(value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stepping() {
+        assert_eq!(5u8.step_up(3), 8);
+        assert_eq!(254u8.step_up(3), 255);
+        assert_eq!(1u8.step_down(3), 0);
+        assert_eq!((-127i8).step_down(3), -128);
+        assert_eq!(clamp(11, Some(0), Some(10)), 10);
+        assert_eq!(clamp(-1, Some(0), Some(10)), 0);
+        assert_eq!(clamp(5, Some(0), None), 5);
+    }
+}
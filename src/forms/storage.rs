@@ -1,5 +1,6 @@
 use crate::validations::{ValidationError, Validator};
 use std::{cell::RefCell, rc::Rc};
+use yew::Callback;
 
 #[derive(Debug, Default, Clone)]
 pub struct FormStorage<T>
@@ -15,8 +16,58 @@ where
     T: std::fmt::Debug + Default + Clone,
 {
     value: T,
-    dirty: bool,
+    original_value: T,
+    touched: bool,
     invalid_value: bool,
+    subscribers: Vec<(usize, Callback<T>)>,
+    last_subscriber_id: usize,
+    /// Subscriptions that keep a derived storage up to date.
+    dependencies: Vec<Rc<SubscriptionHandle>>,
+}
+
+/// A reference to a `FormStorage` that does not keep it alive.
+pub(crate) struct WeakFormStorage<T>
+where
+    T: std::fmt::Debug + Default + Clone,
+{
+    value: std::rc::Weak<RefCell<FormStorageBacking<T>>>,
+}
+
+impl<T> WeakFormStorage<T>
+where
+    T: std::fmt::Debug + Default + Clone,
+{
+    pub fn upgrade(&self) -> Option<FormStorage<T>> {
+        self.value.upgrade().map(|value| FormStorage { value })
+    }
+}
+
+/// Removes the subscription it was returned for when dropped.
+#[must_use = "the subscription is removed when the handle is dropped"]
+pub struct SubscriptionHandle {
+    unsubscribe: Option<Box<dyn FnOnce()>>,
+}
+
+impl SubscriptionHandle {
+    pub(crate) fn new<U: FnOnce() + 'static>(unsubscribe: U) -> Self {
+        Self {
+            unsubscribe: Some(Box::new(unsubscribe)),
+        }
+    }
+}
+
+impl std::fmt::Debug for SubscriptionHandle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SubscriptionHandle").finish()
+    }
+}
+
+impl Drop for SubscriptionHandle {
+    fn drop(&mut self) {
+        if let Some(unsubscribe) = self.unsubscribe.take() {
+            unsubscribe();
+        }
+    }
 }
 
 impl<T> FormStorage<T>
@@ -26,26 +77,174 @@ where
     pub fn new(value: T) -> Self {
         Self {
             value: Rc::new(RefCell::new(FormStorageBacking {
+                original_value: value.clone(),
                 value,
-                dirty: false,
+                touched: false,
                 invalid_value: false,
+                subscribers: Vec::new(),
+                last_subscriber_id: 0,
+                dependencies: Vec::new(),
             })),
         }
     }
 
+    /// Creates a storage whose value is computed from `a` and `b`, and recomputed whenever either changes.
+    pub fn derived<A, B, F>(a: &FormStorage<A>, b: &FormStorage<B>, compute: F) -> Self
+    where
+        T: 'static,
+        A: std::fmt::Debug + Default + Clone + PartialEq + 'static,
+        B: std::fmt::Debug + Default + Clone + PartialEq + 'static,
+        F: Fn(&A, &B) -> T + 'static,
+    {
+        let derived = Self::new(compute(&a.unchecked_value(), &b.unchecked_value()));
+
+        // Only weak references are captured so that the inputs and the derived storage don't keep each other alive
+        let weak_a = Rc::downgrade(&a.value);
+        let weak_b = Rc::downgrade(&b.value);
+        let weak_derived = Rc::downgrade(&derived.value);
+        let recompute = Rc::new(move || {
+            if let (Some(a), Some(b), Some(derived)) =
+                (weak_a.upgrade(), weak_b.upgrade(), weak_derived.upgrade())
+            {
+                let value = compute(&a.borrow().value, &b.borrow().value);
+                FormStorage { value: derived }.update(value);
+            }
+        });
+
+        let on_a = {
+            let recompute = recompute.clone();
+            a.subscribe(Callback::from(move |_| recompute()))
+        };
+        let on_b = b.subscribe(Callback::from(move |_| recompute()));
+        derived
+            .value
+            .borrow_mut()
+            .dependencies
+            .extend(vec![Rc::new(on_a), Rc::new(on_b)]);
+        derived
+    }
+
+    /// Invokes `callback` with the new value each time the value changes, until the returned handle is dropped.
+    pub fn subscribe(&self, callback: Callback<T>) -> SubscriptionHandle
+    where
+        T: 'static,
+    {
+        let id = {
+            let mut backing = self.value.borrow_mut();
+            backing.last_subscriber_id += 1;
+            let id = backing.last_subscriber_id;
+            backing.subscribers.push((id, callback));
+            id
+        };
+        let backing = Rc::downgrade(&self.value);
+        SubscriptionHandle::new(move || {
+            if let Some(backing) = backing.upgrade() {
+                backing
+                    .borrow_mut()
+                    .subscribers
+                    .retain(|(subscriber_id, _)| *subscriber_id != id);
+            }
+        })
+    }
+
+    pub(crate) fn downgrade(&self) -> WeakFormStorage<T> {
+        WeakFormStorage {
+            value: Rc::downgrade(&self.value),
+        }
+    }
+
     pub fn update(&mut self, new_value: T) {
-        let mut backing = self.value.borrow_mut();
-        backing.dirty = backing.dirty || backing.value.eq(&new_value);
-        backing.value = new_value;
+        let changed = {
+            let mut backing = self.value.borrow_mut();
+            let changed = backing.value != new_value;
+            backing.value = new_value;
+            changed
+        };
+        if changed {
+            self.notify();
+        }
+    }
+
+    fn notify(&self) {
+        // The borrow must be released before emitting, as subscribers may read or update this storage
+        let (value, subscribers) = {
+            let backing = self.value.borrow();
+            let subscribers = backing
+                .subscribers
+                .iter()
+                .map(|(_, callback)| callback.clone())
+                .collect::<Vec<_>>();
+            (backing.value.clone(), subscribers)
+        };
+        for subscriber in subscribers {
+            subscriber.emit(value.clone());
+        }
+    }
+
+    /// Marks the field as having been visited by the user, typically when the input loses focus.
+    pub fn touch(&mut self) {
+        self.value.borrow_mut().touched = true;
+    }
+
+    /// Returns true if the current value differs from the value the storage was created or last reset with.
+    pub fn is_dirty(&self) -> bool {
+        let backing = self.value.borrow();
+        backing.invalid_value || backing.value != backing.original_value
+    }
+
+    pub fn is_touched(&self) -> bool {
+        self.value.borrow().touched
     }
 
+    pub fn original_value(&self) -> T {
+        self.value.borrow().original_value.clone()
+    }
+
+    /// Restores the original value and clears the touched and invalid flags.
+    pub fn reset(&mut self) {
+        let original_value = {
+            let mut backing = self.value.borrow_mut();
+            backing.touched = false;
+            backing.original_value.clone()
+        };
+        self.update_with_invalid_hint(original_value, false);
+    }
+
+    /// Replaces both the current and original values, such as after the model has been saved.
+    pub fn reset_to(&mut self, value: T) {
+        {
+            let mut backing = self.value.borrow_mut();
+            backing.original_value = value.clone();
+            backing.touched = false;
+        }
+        self.update_with_invalid_hint(value, false);
+    }
+
+    /// Updates the value along with whether the input it came from could be parsed. Subscribers are notified
+    /// if either changed.
     pub fn update_with_invalid_hint(&mut self, new_value: T, invalid: bool) {
-        self.update(new_value);
-        self.value.borrow_mut().invalid_value = invalid;
+        let changed = {
+            let mut backing = self.value.borrow_mut();
+            let changed = backing.value != new_value || backing.invalid_value != invalid;
+            backing.value = new_value;
+            backing.invalid_value = invalid;
+            changed
+        };
+        if changed {
+            self.notify();
+        }
     }
 
     pub fn update_invalid_hint(&mut self, invalid: bool) {
-        self.value.borrow_mut().invalid_value = invalid;
+        let changed = {
+            let mut backing = self.value.borrow_mut();
+            let changed = backing.invalid_value != invalid;
+            backing.invalid_value = invalid;
+            changed
+        };
+        if changed {
+            self.notify();
+        }
     }
 
     pub fn value(&self) -> Result<T, ValidationError> {
@@ -70,3 +269,169 @@ where
         Ok(())
     }
 }
+
+/// Serializes only the current value.
+#[cfg(feature = "serde")]
+impl<T> serde::Serialize for FormStorage<T>
+where
+    T: std::fmt::Debug + Default + Clone + serde::Serialize,
+{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.value.borrow().value.serialize(serializer)
+    }
+}
+
+/// Deserializes a value into a new storage, treating it as the original value.
+#[cfg(feature = "serde")]
+impl<'de, T> serde::Deserialize<'de> for FormStorage<T>
+where
+    T: std::fmt::Debug + Default + Clone + PartialEq + serde::Deserialize<'de>,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        T::deserialize(deserializer).map(Self::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dirty_tracking() {
+        let mut storage = FormStorage::new(String::from("original"));
+        assert!(!storage.is_dirty());
+
+        storage.update(String::from("original"));
+        assert!(
+            !storage.is_dirty(),
+            "setting the same value is not a change"
+        );
+
+        storage.update(String::from("changed"));
+        assert!(storage.is_dirty());
+        assert_eq!(storage.original_value(), "original");
+
+        storage.update(String::from("original"));
+        assert!(
+            !storage.is_dirty(),
+            "restoring the original value is not a change"
+        );
+
+        storage.update_invalid_hint(true);
+        assert!(storage.is_dirty(), "unparsable input is a change");
+    }
+
+    #[test]
+    fn touched_tracking() {
+        let mut storage = FormStorage::new(1u32);
+        assert!(!storage.is_touched());
+        storage.touch();
+        assert!(storage.is_touched());
+        assert!(!storage.is_dirty(), "touching does not change the value");
+    }
+
+    #[test]
+    fn resetting() {
+        let mut storage = FormStorage::new(1u32);
+        storage.update(2);
+        storage.touch();
+        storage.update_invalid_hint(true);
+        storage.reset();
+        assert_eq!(storage.unchecked_value(), 1);
+        assert!(!storage.is_dirty());
+        assert!(!storage.is_touched());
+        storage.value().expect("reset clears the invalid hint");
+
+        storage.update(3);
+        storage.reset_to(3);
+        assert_eq!(storage.original_value(), 3);
+        assert!(!storage.is_dirty());
+    }
+
+    #[test]
+    fn subscriptions() {
+        let received = Rc::new(RefCell::new(Vec::new()));
+        let mut storage = FormStorage::new(1u32);
+        let handle = {
+            let received = received.clone();
+            storage.subscribe(Callback::from(move |value| {
+                received.borrow_mut().push(value)
+            }))
+        };
+
+        storage.update(2);
+        storage.update(2);
+        storage.update(3);
+        assert_eq!(&*received.borrow(), &[2, 3], "only changes are published");
+
+        drop(handle);
+        storage.update(4);
+        assert_eq!(
+            &*received.borrow(),
+            &[2, 3],
+            "dropping the handle unsubscribes"
+        );
+    }
+
+    #[test]
+    fn invalid_hint_notifications() {
+        let validity = Rc::new(RefCell::new(Vec::new()));
+        let mut storage = FormStorage::new(1u32);
+        let _handle = {
+            let validity = validity.clone();
+            let observed = storage.clone();
+            storage.subscribe(Callback::from(move |_| {
+                validity.borrow_mut().push(observed.validate().is_ok())
+            }))
+        };
+
+        storage.update_with_invalid_hint(2, true);
+        storage.update_invalid_hint(true);
+        storage.update_with_invalid_hint(2, false);
+        storage.update_invalid_hint(true);
+        storage.reset();
+        assert_eq!(
+            &*validity.borrow(),
+            &[false, true, false, true],
+            "subscribers see the new validity whenever it changes"
+        );
+    }
+
+    #[test]
+    fn derived_storage() {
+        let mut quantity = FormStorage::new(2u32);
+        let mut price = FormStorage::new(5u32);
+        let total = FormStorage::derived(&quantity, &price, |quantity, price| quantity * price);
+        assert_eq!(total.unchecked_value(), 10);
+
+        quantity.update(3);
+        assert_eq!(total.unchecked_value(), 15);
+        price.update(1);
+        assert_eq!(total.unchecked_value(), 3);
+
+        // Derived storages can themselves be inputs
+        let mut enabled = FormStorage::new(false);
+        let enabled_total =
+            FormStorage::derived(
+                &total,
+                &enabled,
+                |total, enabled| if *enabled { *total } else { 0 },
+            );
+        assert_eq!(enabled_total.unchecked_value(), 0);
+        enabled.update(true);
+        assert_eq!(enabled_total.unchecked_value(), 3);
+        quantity.update(4);
+        assert_eq!(enabled_total.unchecked_value(), 4);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serializing_storage() {
+        let storage = FormStorage::new(vec![1, 2]);
+        let json = serde_json::to_string(&storage).unwrap();
+        assert_eq!(json, "[1,2]");
+        let restored = serde_json::from_str::<FormStorage<Vec<u32>>>(&json).unwrap();
+        assert_eq!(restored.unchecked_value(), vec![1, 2]);
+        assert!(!restored.is_dirty());
+    }
+}
@@ -0,0 +1,93 @@
+use super::storage::FormStorage;
+use crate::forms::FormField;
+use std::{collections::HashMap, rc::Rc};
+use yew::prelude::*;
+
+/// A checkbox styled with the [bulma-switch](https://wikiki.github.io/form/switch/) extension.
+pub struct Switch<T>
+where
+    T: FormField,
+{
+    props: Props<T>,
+    link: ComponentLink<Self>,
+}
+
+#[derive(Clone, Properties)]
+pub struct Props<T>
+where
+    T: FormField,
+{
+    #[prop_or_default]
+    pub on_value_changed: Callback<bool>,
+    pub storage: FormStorage<bool>,
+    pub field: T,
+    pub errors: Option<Rc<HashMap<T, Vec<Rc<Html>>>>>,
+    #[prop_or_default]
+    pub label: String,
+    #[prop_or_default]
+    pub disabled: bool,
+    /// Additional classes for the switch, such as `is-rounded` or `is-success`.
+    #[prop_or_default]
+    pub css_class: String,
+}
+
+pub enum Message {
+    Toggled,
+    Blurred,
+}
+
+impl<T> Component for Switch<T>
+where
+    T: FormField,
+{
+    type Message = Message;
+    type Properties = Props<T>;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self { props, link }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Message::Blurred => self.props.storage.touch(),
+            Message::Toggled => {
+                let checked = !self.props.storage.unchecked_value();
+                self.props.storage.update(checked);
+                self.props.on_value_changed.emit(checked);
+            }
+        }
+        true
+    }
+
+    fn view(&self) -> Html {
+        let has_errors = self
+            .props
+            .errors
+            .as_ref()
+            .map(|errors| errors.contains_key(&self.props.field))
+            .unwrap_or_default();
+        let css_class = if has_errors {
+            format!("switch {} is-danger", self.props.css_class)
+        } else {
+            format!("switch {}", self.props.css_class)
+        };
+        html! {
+            <div class="control">
+                <input
+                    id=self.props.field.form_id()
+                    class=css_class
+                    type="checkbox"
+                    checked=self.props.storage.unchecked_value()
+                    onclick=self.link.callback(|_| Message::Toggled)
+                    onblur=self.link.callback(|_| Message::Blurred)
+                    disabled=self.props.disabled />
+                <label for=self.props.field.form_id()>{ &self.props.label }</label>
+            </div>
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        self.props = props;
+        true
+    }
+}
@@ -0,0 +1,222 @@
+use std::{collections::HashMap, rc::Rc};
+
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+use crate::{forms::FormField, validations::Validator};
+
+use super::storage::FormStorage;
+
+/// Builds a validator for a tag before it is added, for example `|tag| Box::new(tag.is_present())`.
+pub type TagValidator = Rc<dyn Fn(String) -> Box<dyn Validator>>;
+
+pub struct TagsInput<T>
+where
+    T: FormField,
+{
+    props: Props<T>,
+    text_value: String,
+    tag_error: Option<String>,
+    input: NodeRef,
+    link: ComponentLink<Self>,
+}
+
+#[derive(Clone, Properties)]
+pub struct Props<T>
+where
+    T: FormField,
+{
+    #[prop_or_default]
+    pub on_value_changed: Callback<Vec<String>>,
+    pub storage: FormStorage<Vec<String>>,
+    pub field: T,
+    pub errors: Option<Rc<HashMap<T, Vec<Rc<Html>>>>>,
+    #[prop_or_default]
+    pub placeholder: String,
+    #[prop_or_default]
+    pub disabled: bool,
+    #[prop_or_default]
+    pub tag_validator: Option<TagValidator>,
+    /// Additional classes for each tag, such as `is-info`.
+    #[prop_or_default]
+    pub tag_css_class: String,
+}
+
+pub enum Message {
+    KeyPressed,
+    KeyDown(KeyboardEvent),
+    Remove(usize),
+    Blurred,
+}
+
+impl<T> Component for TagsInput<T>
+where
+    T: FormField,
+{
+    type Message = Message;
+    type Properties = Props<T>;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            props,
+            link,
+            text_value: String::default(),
+            tag_error: None,
+            input: NodeRef::default(),
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Message::Blurred => {
+                self.props.storage.touch();
+                false
+            }
+            Message::KeyPressed => {
+                if let Some(input) = self.input.cast::<HtmlInputElement>() {
+                    self.text_value = input.value();
+                    // Pasted text may contain several comma separated tags
+                    if self.text_value.contains(',') {
+                        let pending = std::mem::take(&mut self.text_value);
+                        let mut parts = pending.split(',').peekable();
+                        while let Some(part) = parts.next() {
+                            if parts.peek().is_some() {
+                                if !self.add_tag(part) {
+                                    self.text_value =
+                                        parts.fold(part.to_owned(), |text, part| text + "," + part);
+                                    break;
+                                }
+                            } else {
+                                self.text_value = part.to_owned();
+                            }
+                        }
+                        return true;
+                    }
+                    if self.tag_error.take().is_some() {
+                        return true;
+                    }
+                }
+                false
+            }
+            Message::KeyDown(event) => match event.key().as_str() {
+                "Enter" => {
+                    event.prevent_default();
+                    let pending = self.text_value.clone();
+                    if self.add_tag(&pending) {
+                        self.text_value.clear();
+                    }
+                    true
+                }
+                "Backspace" if self.text_value.is_empty() => {
+                    let mut tags = self.props.storage.unchecked_value();
+                    if tags.pop().is_some() {
+                        self.store(tags);
+                        true
+                    } else {
+                        false
+                    }
+                }
+                _ => false,
+            },
+            Message::Remove(index) => {
+                let mut tags = self.props.storage.unchecked_value();
+                if index < tags.len() {
+                    tags.remove(index);
+                    self.store(tags);
+                }
+                true
+            }
+        }
+    }
+
+    fn view(&self) -> Html {
+        let has_errors = self.tag_error.is_some()
+            || self
+                .props
+                .errors
+                .as_ref()
+                .map(|errors| errors.contains_key(&self.props.field))
+                .unwrap_or_default();
+        let css_class = if has_errors {
+            "input is-danger"
+        } else {
+            "input"
+        };
+        let tag_error = match &self.tag_error {
+            Some(error) => html! {<p class="help is-danger">{ error }</p>},
+            None => Html::default(),
+        };
+        let tags = self.props.storage.unchecked_value();
+        html! {
+            <div class="control">
+                <div class="tags">
+                    { tags.iter().enumerate().map(|(index, tag)| self.render_tag(index, tag)).collect::<Html>() }
+                </div>
+                <input
+                    id=self.props.field.form_id()
+                    class=css_class
+                    ref=self.input.clone()
+                    type="text"
+                    value=&self.text_value
+                    placeholder=&self.props.placeholder
+                    oninput=self.link.callback(|_| Message::KeyPressed)
+                    onblur=self.link.callback(|_| Message::Blurred)
+                    onkeydown=self.link.callback(Message::KeyDown)
+                    disabled=self.props.disabled />
+                { tag_error }
+            </div>
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        self.props = props;
+        true
+    }
+}
+
+impl<T> TagsInput<T>
+where
+    T: FormField,
+{
+    /// Returns false if the tag was rejected by the validator.
+    fn add_tag(&mut self, tag: &str) -> bool {
+        let tag = tag.trim();
+        if tag.is_empty() {
+            return true;
+        }
+
+        if let Some(validator) = &self.props.tag_validator {
+            if let Err(error) = validator(tag.to_owned()).validate() {
+                self.tag_error = Some(format!("{} {}", tag, error));
+                return false;
+            }
+        }
+        self.tag_error = None;
+
+        let mut tags = self.props.storage.unchecked_value();
+        if !tags.iter().any(|existing| existing == tag) {
+            tags.push(tag.to_owned());
+            self.store(tags);
+        }
+        true
+    }
+
+    fn store(&mut self, tags: Vec<String>) {
+        self.props.storage.update(tags.clone());
+        self.props.on_value_changed.emit(tags);
+    }
+
+    fn render_tag(&self, index: usize, tag: &str) -> Html {
+        html! {
+            <span class=format!("tag {}", self.props.tag_css_class)>
+                { tag }
+                <button
+                    class="delete is-small"
+                    type="button"
+                    aria-label="remove"
+                    disabled=self.props.disabled
+                    onclick=self.link.callback(move |_| Message::Remove(index)) />
+            </span>
+        }
+    }
+}
@@ -0,0 +1,181 @@
+use std::{collections::HashMap, rc::Rc};
+
+use web_sys::HtmlTextAreaElement;
+use yew::prelude::*;
+
+use crate::forms::FormField;
+
+use super::storage::FormStorage;
+
+pub struct TextArea<T>
+where
+    T: FormField,
+{
+    props: Props<T>,
+    text_value: String,
+    input: NodeRef,
+    link: ComponentLink<Self>,
+}
+
+#[derive(Clone, Properties)]
+pub struct Props<T>
+where
+    T: FormField,
+{
+    #[prop_or_default]
+    pub on_value_changed: Callback<Option<String>>,
+    pub storage: FormStorage<Option<String>>,
+    pub field: T,
+    pub errors: Option<Rc<HashMap<T, Vec<Rc<Html>>>>>,
+    #[prop_or_default]
+    pub placeholder: String,
+    #[prop_or_default]
+    pub rows: Option<u32>,
+    /// Resizes the textarea to fit its content as the user types.
+    #[prop_or_default]
+    pub auto_grow: bool,
+    /// When set, a "length / max_length" counter is shown below the textarea.
+    #[prop_or_default]
+    pub max_length: Option<usize>,
+    #[prop_or_default]
+    pub disabled: bool,
+    #[prop_or_default]
+    pub readonly: bool,
+    #[prop_or_default]
+    pub autofocus: bool,
+}
+
+pub enum Message {
+    KeyPressed,
+    Blurred,
+}
+
+impl<T> Component for TextArea<T>
+where
+    T: FormField,
+{
+    type Message = Message;
+    type Properties = Props<T>;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let text_value = props.storage.unchecked_value().unwrap_or_default();
+        TextArea {
+            props,
+            link,
+            input: NodeRef::default(),
+            text_value,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Message::Blurred => self.props.storage.touch(),
+            Message::KeyPressed => {
+                if let Some(input) = self.input.cast::<HtmlTextAreaElement>() {
+                    self.text_value = input.value();
+                    let value = if self.text_value.is_empty() {
+                        None
+                    } else {
+                        Some(self.text_value.clone())
+                    };
+                    self.props.storage.update(value.clone());
+                    self.props.on_value_changed.emit(value);
+                    self.resize_to_fit();
+                }
+            }
+        }
+        self.props.max_length.is_some()
+    }
+
+    fn view(&self) -> Html {
+        let has_errors = self
+            .props
+            .errors
+            .as_ref()
+            .map(|errors| errors.contains_key(&self.props.field))
+            .unwrap_or_default();
+        let css_class = if has_errors {
+            "textarea is-danger"
+        } else {
+            "textarea"
+        };
+        let rows = self
+            .props
+            .rows
+            .map(|rows| rows.to_string())
+            .unwrap_or_default();
+        html! {
+            <>
+                <div class="control">
+                    <textarea
+                        id=self.props.field.form_id()
+                        class=css_class
+                        ref=self.input.clone()
+                        rows=rows
+                        value=self.text_value
+                        placeholder=&self.props.placeholder
+                        onchange=self.link.callback(|_| Message::KeyPressed)
+                        onblur=self.link.callback(|_| Message::Blurred)
+                        oninput=self.link.callback(|_| Message::KeyPressed)
+                        disabled=self.props.disabled
+                        readonly=self.props.readonly />
+                </div>
+                { self.counter() }
+            </>
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        self.text_value = props.storage.unchecked_value().unwrap_or_default();
+        self.props = props;
+        true
+    }
+
+    fn rendered(&mut self, first_render: bool) {
+        if first_render {
+            self.resize_to_fit();
+            if self.props.autofocus {
+                if let Some(input) = self.input.cast::<HtmlTextAreaElement>() {
+                    let _ = input.focus();
+                }
+            }
+        }
+    }
+}
+
+impl<T> TextArea<T>
+where
+    T: FormField,
+{
+    fn counter(&self) -> Html {
+        match self.props.max_length {
+            Some(max_length) => {
+                let length = self.text_value.chars().count();
+                let css_class = if length > max_length {
+                    "help is-danger"
+                } else {
+                    "help"
+                };
+                html! {
+                    <p class=css_class>{ format!("{} / {}", length, max_length) }</p>
+                }
+            }
+            None => Html::default(),
+        }
+    }
+
+    fn resize_to_fit(&self) {
+        if !self.props.auto_grow {
+            return;
+        }
+
+        if let Some(input) = self.input.cast::<HtmlTextAreaElement>() {
+            // Collapse the textarea first so that scroll_height reflects the content, not the current size
+            let _ = input.set_attribute("style", "height: auto; overflow: hidden");
+            let _ = input.set_attribute(
+                "style",
+                &format!("height: {}px; overflow: hidden", input.scroll_height()),
+            );
+        }
+    }
+}
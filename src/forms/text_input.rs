@@ -1,7 +1,7 @@
 use std::{collections::HashMap, rc::Rc, str::FromStr};
 
 use web_sys::HtmlInputElement;
-use yew::prelude::*;
+use yew::{prelude::*, virtual_dom::VNode};
 
 use crate::forms::FormField;
 
@@ -26,6 +26,9 @@ where
 {
     #[prop_or_default]
     pub on_value_changed: Callback<Option<V>>,
+    /// Invoked after the storage has been marked as touched.
+    #[prop_or_default]
+    pub on_blur: Callback<()>,
     pub storage: FormStorage<Option<V>>,
     pub field: T,
     pub errors: Option<Rc<HashMap<T, Vec<Rc<Html>>>>>,
@@ -37,10 +40,52 @@ where
     pub readonly: bool,
     #[prop_or_default]
     pub autofocus: bool,
+    #[prop_or_default]
+    pub input_type: InputType,
+    #[prop_or_default]
+    pub name: Option<String>,
+    #[prop_or_default]
+    pub autocomplete: Option<String>,
+    /// Only used when `input_type` is `InputType::Number`
+    #[prop_or_default]
+    pub min: Option<String>,
+    /// Only used when `input_type` is `InputType::Number`
+    #[prop_or_default]
+    pub max: Option<String>,
+    /// Only used when `input_type` is `InputType::Number`
+    #[prop_or_default]
+    pub step: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub enum InputType {
+    #[default]
+    Text,
+    Password,
+    Email,
+    Number,
+    Tel,
+    Url,
+    Search,
+}
+
+impl InputType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            InputType::Text => "text",
+            InputType::Password => "password",
+            InputType::Email => "email",
+            InputType::Number => "number",
+            InputType::Tel => "tel",
+            InputType::Url => "url",
+            InputType::Search => "search",
+        }
+    }
 }
 
 pub enum Message {
     KeyPressed,
+    Blurred,
 }
 
 impl<T, V> Component for TextInput<T, V>
@@ -68,6 +113,10 @@ where
 
     fn update(&mut self, msg: Self::Message) -> ShouldRender {
         match msg {
+            Message::Blurred => {
+                self.props.storage.touch();
+                self.props.on_blur.emit(());
+            }
             Message::KeyPressed => {
                 if let Some(input) = self.input.cast::<HtmlInputElement>() {
                     self.text_value = input.value();
@@ -94,31 +143,53 @@ where
     }
 
     fn view(&self) -> Html {
-        let errors = self
+        let has_errors = self
             .props
             .errors
             .as_ref()
-            .map(|errors| errors.get(&self.props.field).cloned());
-        let css_class = match &errors {
-            Some(errors) => match errors {
-                Some(_) => "input is-danger",
-                None => "input",
-            },
-            None => "input",
+            .map(|errors| errors.contains_key(&self.props.field))
+            .unwrap_or_default();
+        let css_class = if has_errors {
+            "input is-danger"
+        } else {
+            "input"
         };
-        html! {
-            <div class="control">
+        let mut input = html! {
                 <input
                     id=self.props.field.form_id()
                     class=css_class
                     ref=self.input.clone()
-                    type="text"
+                    type=self.props.input_type.as_str()
                     value=self.text_value
                     placeholder=&self.props.placeholder
                     onchange=self.link.callback(|_| Message::KeyPressed)
+                    onblur=self.link.callback(|_| Message::Blurred)
                     oninput=self.link.callback(|_| Message::KeyPressed)
                     disabled=self.props.disabled
                     readonly=self.props.readonly />
+        };
+        if let VNode::VTag(tag) = &mut input {
+            // Optional attributes are only written when set, as an empty `min` or `step` is not the same as none
+            let mut optional_attributes = vec![
+                ("name", &self.props.name),
+                ("autocomplete", &self.props.autocomplete),
+            ];
+            if self.props.input_type == InputType::Number {
+                optional_attributes.extend(vec![
+                    ("min", &self.props.min),
+                    ("max", &self.props.max),
+                    ("step", &self.props.step),
+                ]);
+            }
+            for (name, value) in optional_attributes {
+                if let Some(value) = value {
+                    tag.add_attribute(name, value);
+                }
+            }
+        }
+        html! {
+            <div class="control">
+                { input }
             </div>
         }
     }
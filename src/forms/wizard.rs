@@ -0,0 +1,354 @@
+use std::rc::Rc;
+
+use yew::prelude::*;
+
+use crate::{
+    forms::{
+        button::Button,
+        form::{focus_field, SubmissionState, ValidatorFactory},
+        FormField,
+    },
+    validations::{ErrorSet, FieldError},
+};
+
+/// A page of a `Wizard`.
+#[derive(Clone)]
+pub struct WizardStep<F>
+where
+    F: FormField,
+{
+    pub title: String,
+    pub content: Html,
+    /// Validates the fields shown on this step before the user can advance.
+    pub validator: Option<ValidatorFactory<F>>,
+}
+
+impl<F> WizardStep<F>
+where
+    F: FormField,
+{
+    pub fn new<S: Into<String>>(title: S, content: Html) -> Self {
+        Self {
+            title: title.into(),
+            content,
+            validator: None,
+        }
+    }
+
+    pub fn with_validator<V: Fn() -> crate::validations::ModelValidator<F> + 'static>(
+        mut self,
+        validator: V,
+    ) -> Self {
+        self.validator = Some(Rc::new(validator));
+        self
+    }
+}
+
+/// A form split across several steps, shown with
+/// [bulma-steps](https://wikiki.github.io/components/steps/) indicators.
+///
+/// Advancing to the next step requires the current step to validate. As the step content is
+/// rendered from `FormStorage`s owned by the parent, going back to a previous step keeps the
+/// values entered so far. Submitting on the last step validates every step again, returning to the
+/// first step with errors, and otherwise invokes `on_submit` like `Form` does.
+pub struct Wizard<F>
+where
+    F: FormField,
+{
+    props: Props<F>,
+    current_step: usize,
+    state: SubmissionState,
+    failure: Option<String>,
+    link: ComponentLink<Self>,
+}
+
+#[derive(Clone, Properties)]
+pub struct Props<F>
+where
+    F: FormField,
+{
+    pub steps: Vec<WizardStep<F>>,
+    pub on_submit: Callback<Callback<Result<(), String>>>,
+    /// Invoked with the result of each validation, `None` meaning the validated steps are valid.
+    #[prop_or_default]
+    pub on_errors: Callback<Option<Rc<ErrorSet<F>>>>,
+    #[prop_or_default]
+    pub on_step_changed: Callback<usize>,
+    #[prop_or_default]
+    pub on_state_changed: Callback<SubmissionState>,
+    #[prop_or("Back".to_owned())]
+    pub back_label: String,
+    #[prop_or("Next".to_owned())]
+    pub next_label: String,
+    pub submit_label: String,
+    #[prop_or("is-primary".to_owned())]
+    pub submit_css_class: String,
+    #[prop_or_default]
+    pub disabled: bool,
+}
+
+pub enum Message {
+    Back,
+    Next,
+    GoTo(usize),
+    Submit,
+    Completed(Result<(), String>),
+}
+
+impl<F> Component for Wizard<F>
+where
+    F: FormField,
+{
+    type Message = Message;
+    type Properties = Props<F>;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            props,
+            link,
+            current_step: 0,
+            state: SubmissionState::Idle,
+            failure: None,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Message::Completed(result) => {
+                if self.state != SubmissionState::Submitting {
+                    return false;
+                }
+
+                match result {
+                    Ok(_) => self.set_state(SubmissionState::Succeeded),
+                    Err(message) => {
+                        self.failure = Some(message);
+                        self.set_state(SubmissionState::Failed);
+                    }
+                }
+            }
+            _ if self.state.is_processing() => return false,
+            Message::Back => {
+                if self.current_step > 0 {
+                    self.set_step(self.current_step - 1);
+                }
+            }
+            Message::GoTo(step) => {
+                // Only steps that have already been completed can be jumped to
+                if step < self.current_step {
+                    self.set_step(step);
+                }
+            }
+            Message::Next => {
+                if self.current_step + 1 >= self.props.steps.len() {
+                    return false;
+                }
+                let validator = self.props.steps[self.current_step].validator.as_ref();
+                let errors = validator.and_then(|validator| validator().validate());
+                self.props.on_errors.emit(errors.clone());
+                match errors.filter(|errors| errors.is_blocking()) {
+                    Some(errors) => focus_first_error(&errors),
+                    None => self.set_step(self.current_step + 1),
+                }
+            }
+            Message::Submit => {
+                if self.props.disabled {
+                    return false;
+                }
+
+                self.failure = None;
+                self.set_state(SubmissionState::Validating);
+                let (invalid_step, errors) = validate_steps(&self.props.steps);
+                self.props.on_errors.emit(errors.clone());
+                if let (Some(step), Some(errors)) = (invalid_step, errors) {
+                    self.set_step(step);
+                    focus_first_error(&errors);
+                    self.set_state(SubmissionState::Idle);
+                } else {
+                    self.set_state(SubmissionState::Submitting);
+                    self.props
+                        .on_submit
+                        .emit(self.link.callback(Message::Completed));
+                }
+            }
+        }
+        true
+    }
+
+    fn view(&self) -> Html {
+        let is_last_step = self.current_step + 1 >= self.props.steps.len();
+        let content = self
+            .props
+            .steps
+            .get(self.current_step)
+            .map(|step| step.content.clone())
+            .unwrap_or_default();
+        let failure = match &self.failure {
+            Some(message) if !message.is_empty() => {
+                html! {<p class="help is-danger">{ message }</p>}
+            }
+            _ => Html::default(),
+        };
+        let action = if is_last_step {
+            html! {
+                <Button
+                    label=&self.props.submit_label
+                    css_class=&self.props.submit_css_class
+                    disabled=self.props.disabled || self.state.is_processing()
+                    processing=self.state.is_processing()
+                    action=self.link.callback(|e: MouseEvent| {e.prevent_default(); Message::Submit})
+                />
+            }
+        } else {
+            html! {
+                <Button
+                    label=&self.props.next_label
+                    css_class="is-primary"
+                    disabled=self.props.disabled
+                    action=self.link.callback(|e: MouseEvent| {e.prevent_default(); Message::Next})
+                />
+            }
+        };
+        html! {
+            <form onsubmit=self.link.callback(move |e: FocusEvent| {
+                e.prevent_default();
+                if is_last_step { Message::Submit } else { Message::Next }
+            })>
+                <div class="steps">
+                    { self.props.steps.iter().enumerate().map(|(index, step)| self.render_indicator(index, step)).collect::<Html>() }
+                </div>
+                <div class="wizard-step">
+                    { content }
+                </div>
+                <div class="field is-grouped">
+                    <Button
+                        label=&self.props.back_label
+                        disabled=self.current_step == 0 || self.state.is_processing()
+                        action=self.link.callback(|e: MouseEvent| {e.prevent_default(); Message::Back})
+                    />
+                    { action }
+                </div>
+                { failure }
+            </form>
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        self.props = props;
+        if self.current_step >= self.props.steps.len() {
+            self.current_step = self.props.steps.len().saturating_sub(1);
+        }
+        true
+    }
+}
+
+impl<F> Wizard<F>
+where
+    F: FormField,
+{
+    fn set_step(&mut self, step: usize) {
+        if self.current_step != step {
+            self.current_step = step;
+            self.props.on_step_changed.emit(step);
+        }
+    }
+
+    fn set_state(&mut self, state: SubmissionState) {
+        self.state = state;
+        self.props.on_state_changed.emit(state);
+    }
+
+    fn render_indicator(&self, index: usize, step: &WizardStep<F>) -> Html {
+        let mut css_class = String::from("step-item");
+        if index < self.current_step {
+            css_class.push_str(" is-completed");
+        } else if index == self.current_step {
+            css_class.push_str(" is-active");
+        }
+        html! {
+            <div class=css_class onclick=self.link.callback(move |_| Message::GoTo(index))>
+                <div class="step-marker">{ index + 1 }</div>
+                <div class="step-details">
+                    <p class="step-title">{ &step.title }</p>
+                </div>
+            </div>
+        }
+    }
+}
+
+fn focus_first_error<F: FormField>(errors: &ErrorSet<F>) {
+    if let Some(error) = errors.errors().iter().find(|error| error.is_blocking()) {
+        focus_field(error.primary_field());
+    }
+}
+
+/// Validates every step, returning the index of the first step with blocking errors along with the errors of all
+/// steps.
+fn validate_steps<F: FormField>(
+    steps: &[WizardStep<F>],
+) -> (Option<usize>, Option<Rc<ErrorSet<F>>>) {
+    let mut invalid_step = None;
+    let mut errors = Vec::new();
+    for (index, step) in steps.iter().enumerate() {
+        if let Some(validator) = &step.validator {
+            let step_errors = validator().into_errors();
+            if invalid_step.is_none() && step_errors.iter().any(FieldError::is_blocking) {
+                invalid_step = Some(index);
+            }
+            errors.extend(step_errors);
+        }
+    }
+    (invalid_step, ErrorSet::from_errors(errors))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        forms::{storage::FormStorage, testing::Fields},
+        validations::{ModelValidator, Validatable},
+    };
+
+    #[test]
+    fn validating_steps() {
+        let name = FormStorage::new(Some(String::from("jon")));
+        let email = FormStorage::<Option<String>>::default();
+        let company = FormStorage::<Option<String>>::default();
+        let step = |field: Fields, storage: &FormStorage<Option<String>>| {
+            let storage = storage.clone();
+            WizardStep::new(format!("{:?}", field), Html::default()).with_validator(move || {
+                ModelValidator::default().with_field(field, storage.is_present())
+            })
+        };
+        let steps = vec![
+            step(Fields::Name, &name),
+            WizardStep::new("Welcome", Html::default()),
+            step(Fields::Email, &email),
+            step(Fields::Company, &company),
+        ];
+
+        let (invalid_step, errors) = validate_steps(&steps);
+        assert_eq!(invalid_step, Some(2));
+        let fields = errors
+            .unwrap()
+            .errors()
+            .iter()
+            .map(|error| error.primary_field())
+            .collect::<Vec<_>>();
+        assert_eq!(fields, vec![Fields::Email, Fields::Company]);
+
+        email.clone().update(Some(String::from("jon@example.com")));
+        company.clone().update(Some(String::from("Khonsu Labs")));
+        let (invalid_step, errors) = validate_steps(&steps);
+        assert!(invalid_step.is_none());
+        assert!(errors.is_none());
+
+        // Warnings are reported without making their step invalid
+        let steps = vec![WizardStep::new("Name", Html::default()).with_validator(|| {
+            ModelValidator::default().with_warning(Fields::Name, None::<String>.is_present())
+        })];
+        let (invalid_step, errors) = validate_steps(&steps);
+        assert!(invalid_step.is_none());
+        assert!(!errors.unwrap().is_blocking());
+    }
+}
@@ -1,4 +1,7 @@
+#![recursion_limit = "512"]
 pub use include_dir;
+#[cfg(feature = "derive")]
+pub use yew_bulma_derive::BulmaForm;
 pub mod alert;
 pub mod flash;
 pub mod forms;
@@ -9,14 +12,15 @@ pub mod static_page;
 pub mod title;
 pub mod validations;
 
-use chrono::{naive::NaiveDateTime, DateTime, Utc};
+use chrono::{DateTime, Utc};
 
 pub fn wasm_utc_now() -> DateTime<Utc> {
-    let timestamp = js_sys::Date::new_0().get_time();
-    let secs = timestamp.floor();
-    let nanoes = (timestamp - secs) * 1_000_000_000f64;
-    let naivetime = NaiveDateTime::from_timestamp(secs as i64, nanoes as u32);
-    DateTime::from_utc(naivetime, Utc)
+    utc_from_js_timestamp(js_sys::Date::now())
+}
+
+/// Converts a JavaScript timestamp, measured in milliseconds since the Unix epoch.
+pub(crate) fn utc_from_js_timestamp(millis: f64) -> DateTime<Utc> {
+    DateTime::from_timestamp_millis(millis as i64).unwrap_or_default()
 }
 
 pub mod prelude {
@@ -1,25 +1,254 @@
 use std::{
+    borrow::Cow,
     collections::{HashMap, HashSet},
     rc::Rc,
 };
 use thiserror::Error;
+pub mod asynchronous;
 pub mod combinators;
+pub mod comparison;
+pub mod files;
+pub mod format;
+pub mod length;
+pub mod pattern;
 pub mod present;
-use crate::forms::storage::FormStorage;
+pub mod range;
+use crate::forms::{field_array::FieldArray, file::FileRejection, storage::FormStorage};
+use asynchronous::AsyncValidation;
 use combinators::*;
 use present::*;
 
-#[derive(Debug, Error)]
+#[derive(Debug, Clone, PartialEq, Error)]
 pub enum ValidationError {
     #[error("is required")]
     NotPresent,
     #[error("should be blank")]
     NotAbsent,
+    #[error("is too short (minimum is {min})")]
+    TooShort { min: usize, actual: usize },
+    #[error("is too long (maximum is {max})")]
+    TooLong { max: usize, actual: usize },
+    #[error("must be {} {min}", if *.inclusive { "at least" } else { "greater than" })]
+    TooSmall {
+        min: String,
+        inclusive: bool,
+        actual: String,
+    },
+    #[error("must be {} {max}", if *.inclusive { "at most" } else { "less than" })]
+    TooLarge {
+        max: String,
+        inclusive: bool,
+        actual: String,
+    },
+    /// For when a validator combined with `not()` succeeds.
+    #[error("is not allowed")]
+    NotAllowed,
+    /// Every error collected by `all()` or `any()`. `ModelValidator` reports each one separately.
+    #[error("{}", .0.iter().map(ToString::to_string).collect::<Vec<_>>().join(", "))]
+    Multiple(Vec<ValidationError>),
+    #[error("does not match")]
+    Mismatch,
+    #[error("must be different")]
+    NotDistinct,
+    #[error("must be greater")]
+    NotGreater,
+    #[error("is not a valid {format}")]
+    InvalidFormat { format: format::TextFormat },
+    #[error("is not in the expected format")]
+    PatternMismatch { pattern: String },
     /// For when converting from a string to another type fails. Should be validated in another way.
     #[error("invalid value")]
     InvalidValue,
-    #[error("custom error: {0}")]
-    Custom(&'static str),
+    #[error("{name} {reason}")]
+    FileRejected { name: String, reason: FileRejection },
+    /// An `AsyncValidation` hasn't completed yet.
+    #[error("is being checked")]
+    Pending,
+    #[error("{0}")]
+    Custom(CustomError),
+}
+
+impl ValidationError {
+    /// Creates a custom error. See `CustomError` for adding parameters and changing the severity.
+    pub fn custom<C: Into<Cow<'static, str>>, M: Into<Cow<'static, str>>>(
+        code: C,
+        message: M,
+    ) -> Self {
+        ValidationError::Custom(CustomError::new(code, message))
+    }
+
+    /// A stable identifier for the kind of error, suitable as a localization key.
+    pub fn code(&self) -> &str {
+        match self {
+            ValidationError::NotPresent => "not_present",
+            ValidationError::NotAbsent => "not_absent",
+            ValidationError::TooShort { .. } => "too_short",
+            ValidationError::TooLong { .. } => "too_long",
+            ValidationError::TooSmall { .. } => "too_small",
+            ValidationError::TooLarge { .. } => "too_large",
+            ValidationError::NotAllowed => "not_allowed",
+            ValidationError::Multiple(_) => "multiple",
+            ValidationError::Mismatch => "mismatch",
+            ValidationError::NotDistinct => "not_distinct",
+            ValidationError::NotGreater => "not_greater",
+            ValidationError::InvalidFormat { .. } => "invalid_format",
+            ValidationError::PatternMismatch { .. } => "pattern_mismatch",
+            ValidationError::InvalidValue => "invalid_value",
+            ValidationError::FileRejected { .. } => "file_rejected",
+            ValidationError::Pending => "pending",
+            ValidationError::Custom(error) => &error.code,
+        }
+    }
+
+    /// The values describing the error, for interpolating into messages, keyed by name.
+    pub fn params(&self) -> HashMap<String, String> {
+        let params: Vec<(&str, String)> = match self {
+            ValidationError::TooShort { min, actual } => {
+                vec![("min", min.to_string()), ("actual", actual.to_string())]
+            }
+            ValidationError::TooLong { max, actual } => {
+                vec![("max", max.to_string()), ("actual", actual.to_string())]
+            }
+            ValidationError::TooSmall {
+                min,
+                inclusive,
+                actual,
+            } => vec![
+                ("min", min.clone()),
+                ("inclusive", inclusive.to_string()),
+                ("actual", actual.clone()),
+            ],
+            ValidationError::TooLarge {
+                max,
+                inclusive,
+                actual,
+            } => vec![
+                ("max", max.clone()),
+                ("inclusive", inclusive.to_string()),
+                ("actual", actual.clone()),
+            ],
+            ValidationError::InvalidFormat { format } => vec![("format", format.to_string())],
+            ValidationError::PatternMismatch { pattern } => vec![("pattern", pattern.clone())],
+            ValidationError::FileRejected { name, reason } => {
+                vec![("name", name.clone()), ("reason", reason.to_string())]
+            }
+            ValidationError::Custom(error) => return error.params.clone(),
+            _ => Vec::new(),
+        };
+        params
+            .into_iter()
+            .map(|(name, value)| (name.to_owned(), value))
+            .collect()
+    }
+
+    /// Replaces the message with `message`, keeping the code, parameters and severity of this error.
+    pub fn with_message<M: Into<Cow<'static, str>>>(&self, message: M) -> Self {
+        ValidationError::Custom(CustomError {
+            code: Cow::Owned(self.code().to_owned()),
+            message: message.into(),
+            params: self.params(),
+            severity: self.severity(),
+        })
+    }
+
+    /// Returns this error with `severity`. Errors other than `Custom` are converted to a `CustomError` with the
+    /// same code, message and parameters.
+    pub fn with_severity(&self, severity: Severity) -> Self {
+        match self {
+            _ if self.severity() == severity => self.clone(),
+            ValidationError::Custom(error) => {
+                ValidationError::Custom(error.clone().with_severity(severity))
+            }
+            _ => ValidationError::Custom(CustomError {
+                code: Cow::Owned(self.code().to_owned()),
+                message: Cow::Owned(self.to_string()),
+                params: self.params(),
+                severity,
+            }),
+        }
+    }
+
+    /// Custom errors can choose their severity; every other error is `Severity::Error`.
+    pub fn severity(&self) -> Severity {
+        match self {
+            ValidationError::Custom(error) => error.severity,
+            _ => Severity::Error,
+        }
+    }
+}
+
+/// How an error affects the form. Only errors prevent it from being submitted.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash, PartialOrd, Ord)]
+pub enum Severity {
+    Info,
+    Warning,
+    #[default]
+    Error,
+}
+
+impl Severity {
+    pub fn is_blocking(self) -> bool {
+        self == Severity::Error
+    }
+
+    /// The Bulma color modifier for messages of this severity.
+    pub fn css_class(self) -> &'static str {
+        match self {
+            Severity::Info => "is-info",
+            Severity::Warning => "is-warning",
+            Severity::Error => "is-danger",
+        }
+    }
+}
+
+/// An error defined by an application, such as one returned by a server. `{name}` placeholders in the message
+/// are replaced by the parameter of the same name when displayed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CustomError {
+    pub code: Cow<'static, str>,
+    pub message: Cow<'static, str>,
+    pub params: HashMap<String, String>,
+    pub severity: Severity,
+}
+
+impl CustomError {
+    pub fn new<C: Into<Cow<'static, str>>, M: Into<Cow<'static, str>>>(
+        code: C,
+        message: M,
+    ) -> Self {
+        Self {
+            code: code.into(),
+            message: message.into(),
+            params: HashMap::new(),
+            severity: Severity::default(),
+        }
+    }
+
+    pub fn with_param<N: Into<String>, V: ToString>(mut self, name: N, value: V) -> Self {
+        self.params.insert(name.into(), value.to_string());
+        self
+    }
+
+    pub fn with_severity(mut self, severity: Severity) -> Self {
+        self.severity = severity;
+        self
+    }
+}
+
+impl std::fmt::Display for CustomError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut message = self.message.to_string();
+        for (name, value) in self.params.iter() {
+            message = message.replace(&format!("{{{}}}", name), value);
+        }
+        f.write_str(&message)
+    }
+}
+
+impl From<CustomError> for ValidationError {
+    fn from(error: CustomError) -> Self {
+        ValidationError::Custom(error)
+    }
 }
 
 pub trait Validator: std::fmt::Debug {
@@ -40,6 +269,43 @@ pub trait ValidatorCombinators: Sized + Validator {
             right: other,
         }
     }
+
+    /// Fails with `ValidationError::NotAllowed` if this validator succeeds, and succeeds otherwise.
+    fn not(self) -> NotValidation<Self> {
+        NotValidation { validator: self }
+    }
+
+    /// Only validates while `condition` is true, such as requiring a field only when a checkbox is ticked.
+    fn when<C: ValidatableStorage<bool>>(self, condition: &C) -> WhenValidation<Self> {
+        WhenValidation {
+            condition: condition.as_form_storage(),
+            validator: self,
+        }
+    }
+
+    /// Boxes this validator so that it can be combined with others of different types using `all()` or `any()`.
+    fn boxed(self) -> Box<dyn Validator>
+    where
+        Self: 'static,
+    {
+        Box::new(self)
+    }
+
+    /// Succeeds if this validator and every one of `others` succeed, reporting every error. See `all()`.
+    fn all_of<I: IntoIterator<Item = Box<dyn Validator>>>(self, others: I) -> AllValidation
+    where
+        Self: 'static,
+    {
+        all(std::iter::once(self.boxed()).chain(others))
+    }
+
+    /// Succeeds if this validator or any of `others` succeed, reporting every error otherwise. See `any()`.
+    fn any_of<I: IntoIterator<Item = Box<dyn Validator>>>(self, others: I) -> AnyValidation
+    where
+        Self: 'static,
+    {
+        any(std::iter::once(self.boxed()).chain(others))
+    }
 }
 
 impl<T> ValidatorCombinators for T where T: Validator {}
@@ -94,22 +360,14 @@ where
     }
 }
 
-#[derive(Debug)]
-pub struct Field<F, V>
-where
-    F: Copy + std::fmt::Debug,
-    V: std::fmt::Debug,
-{
-    field: F,
-    value: V,
-}
-
 #[derive(Error, Debug)]
 pub struct FieldError<F>
 where
     F: Copy + std::fmt::Debug,
 {
     pub fields: HashSet<F>,
+    /// The field that is focused when the form is submitted, which is also in `fields`.
+    pub primary: F,
     #[source]
     pub error: ValidationError,
 }
@@ -119,7 +377,23 @@ where
     F: Copy + std::fmt::Debug,
 {
     pub fn primary_field(&self) -> F {
-        *self.fields.iter().next().expect("No fields on FieldError")
+        self.primary
+    }
+
+    pub fn code(&self) -> &str {
+        self.error.code()
+    }
+
+    pub fn params(&self) -> HashMap<String, String> {
+        self.error.params()
+    }
+
+    pub fn severity(&self) -> Severity {
+        self.error.severity()
+    }
+
+    pub fn is_blocking(&self) -> bool {
+        self.severity().is_blocking()
     }
 }
 
@@ -147,13 +421,56 @@ impl<F> ErrorSet<F>
 where
     F: Copy + std::fmt::Debug + std::hash::Hash + std::cmp::Eq,
 {
+    pub(crate) fn from_errors(errors: Vec<FieldError<F>>) -> Option<Rc<Self>> {
+        if !errors.is_empty() {
+            Some(Rc::new(Self { errors }))
+        } else {
+            None
+        }
+    }
+
+    pub fn errors(&self) -> &[FieldError<F>] {
+        &self.errors
+    }
+
+    /// Returns true if any error prevents the form from being submitted.
+    pub fn is_blocking(&self) -> bool {
+        self.errors.iter().any(FieldError::is_blocking)
+    }
+
     pub fn translate<T, S>(&self, translator: T) -> Rc<HashMap<F, Vec<Rc<yew::Html>>>>
     where
         T: Fn(&FieldError<F>) -> S,
         S: Into<yew::Html>,
+    {
+        self.translate_matching(|_| true, translator)
+    }
+
+    /// Translates only the messages of `severity`, such as to pass warnings to `Field` separately from errors.
+    pub fn translate_severity<T, S>(
+        &self,
+        severity: Severity,
+        translator: T,
+    ) -> Rc<HashMap<F, Vec<Rc<yew::Html>>>>
+    where
+        T: Fn(&FieldError<F>) -> S,
+        S: Into<yew::Html>,
+    {
+        self.translate_matching(|error| error.severity() == severity, translator)
+    }
+
+    fn translate_matching<P, T, S>(
+        &self,
+        predicate: P,
+        translator: T,
+    ) -> Rc<HashMap<F, Vec<Rc<yew::Html>>>>
+    where
+        P: Fn(&FieldError<F>) -> bool,
+        T: Fn(&FieldError<F>) -> S,
+        S: Into<yew::Html>,
     {
         let mut translated = HashMap::<F, Vec<Rc<yew::Html>>>::new();
-        for error in self.errors.iter() {
+        for error in self.errors.iter().filter(|error| predicate(error)) {
             let error_html = Rc::new(translator(error).into());
             for field in error.fields.iter() {
                 translated
@@ -180,20 +497,32 @@ where
     F: std::fmt::Debug + std::hash::Hash + std::cmp::Eq,
 {
     error_message: Option<&'static str>,
-    fields: HashSet<F>,
+    /// The first field is the primary field of the errors.
+    fields: Vec<F>,
     validator: Box<dyn Validator>,
+    /// Overrides the severity of the validator's errors.
+    severity: Option<Severity>,
+}
+
+enum Validation<F>
+where
+    F: Copy + std::fmt::Debug + std::hash::Hash + std::cmp::Eq,
+{
+    Field(FieldValidator<F>),
+    /// A validator for another set of fields, whose errors are mapped to this validator's fields.
+    Nested(Box<dyn FnOnce() -> Vec<FieldError<F>>>),
 }
 
 pub struct ModelValidator<F>
 where
-    F: std::fmt::Debug + std::hash::Hash + std::cmp::Eq,
+    F: Copy + std::fmt::Debug + std::hash::Hash + std::cmp::Eq,
 {
-    validations: Vec<FieldValidator<F>>,
+    validations: Vec<Validation<F>>,
 }
 
 impl<F> Default for ModelValidator<F>
 where
-    F: std::fmt::Debug + std::hash::Hash + std::cmp::Eq,
+    F: Copy + std::fmt::Debug + std::hash::Hash + std::cmp::Eq,
 {
     fn default() -> Self {
         Self {
@@ -207,19 +536,44 @@ where
     F: Copy + std::fmt::Debug + std::hash::Hash + std::cmp::Eq,
 {
     pub fn with_field<V: Validator + 'static>(mut self, field: F, validator: V) -> Self {
-        self.validations.push(FieldValidator {
-            fields: vec![field].into_iter().collect(),
+        self.validations.push(Validation::Field(FieldValidator {
+            fields: vec![field],
+            validator: Box::new(validator),
+            error_message: None,
+            severity: None,
+        }));
+        self
+    }
+    /// Reports errors of `validator` on `field` with `severity`, such as a warning that doesn't prevent
+    /// submitting the form.
+    pub fn with_severity<V: Validator + 'static>(
+        mut self,
+        field: F,
+        severity: Severity,
+        validator: V,
+    ) -> Self {
+        self.validations.push(Validation::Field(FieldValidator {
+            fields: vec![field],
             validator: Box::new(validator),
             error_message: None,
-        });
+            severity: Some(severity),
+        }));
         self
     }
+    pub fn with_warning<V: Validator + 'static>(self, field: F, validator: V) -> Self {
+        self.with_severity(field, Severity::Warning, validator)
+    }
+    /// Reports the latest result of `validation` on `field`. See `AsyncValidation`'s `Validator` implementation.
+    pub fn with_async(self, field: F, validation: &AsyncValidation) -> Self {
+        self.with_field(field, validation.clone())
+    }
     pub fn with_custom<V: Validator + 'static>(mut self, field: F, validator: V) -> Self {
-        self.validations.push(FieldValidator {
-            fields: vec![field].into_iter().collect(),
+        self.validations.push(Validation::Field(FieldValidator {
+            fields: vec![field],
             validator: Box::new(validator),
             error_message: None,
-        });
+            severity: None,
+        }));
         self
     }
     pub fn with_fields<V: Validator + 'static, I: std::iter::Iterator<Item = F>>(
@@ -228,40 +582,206 @@ where
         validator: V,
         error_message: &'static str,
     ) -> Self {
-        self.validations.push(FieldValidator {
+        self.validations.push(Validation::Field(FieldValidator {
             fields: fields.collect(),
             validator: Box::new(validator),
             error_message: Some(error_message),
-        });
+            severity: None,
+        }));
         self
     }
-    pub fn validate(self) -> Option<Rc<ErrorSet<F>>> {
+    /// Includes the validations of `validator`, reporting its errors on the fields returned by `field`.
+    pub fn with_nested<S, M>(mut self, validator: ModelValidator<S>, field: M) -> Self
+    where
+        F: 'static,
+        S: Copy + std::fmt::Debug + std::hash::Hash + std::cmp::Eq + 'static,
+        M: Fn(S) -> F + 'static,
+    {
+        self.validations.push(Validation::Nested(Box::new(move || {
+            validator
+                .into_errors()
+                .into_iter()
+                .map(|error| FieldError {
+                    fields: error.fields.into_iter().map(&field).collect(),
+                    primary: field(error.primary),
+                    error: error.error,
+                })
+                .collect()
+        })));
+        self
+    }
+    /// Reports errors of a validator comparing two fields, such as `equals()`, on both fields. `field` is the
+    /// primary field, which is focused when the form is submitted.
+    pub fn with_compared<V: Validator + 'static>(
+        mut self,
+        field: F,
+        other_field: F,
+        validator: V,
+    ) -> Self {
+        self.validations.push(Validation::Field(FieldValidator {
+            fields: vec![field, other_field],
+            validator: Box::new(validator),
+            error_message: None,
+            severity: None,
+        }));
+        self
+    }
+    /// Validates each row of `array` with the validator returned by `validator`, reporting errors on the fields
+    /// returned by `field`, such as an `Addresses(usize, AddressField)` variant.
+    pub fn with_indexed<T, S, V, M>(mut self, array: &FieldArray<T>, validator: V, field: M) -> Self
+    where
+        F: 'static,
+        T: std::fmt::Debug + Default + Clone + PartialEq,
+        S: Copy + std::fmt::Debug + std::hash::Hash + std::cmp::Eq + 'static,
+        V: Fn(&FormStorage<T>) -> ModelValidator<S>,
+        M: Fn(usize, S) -> F + Clone + 'static,
+    {
+        for (index, row) in array.rows().iter().enumerate() {
+            let field = field.clone();
+            self = self.with_nested(validator(row), move |sub_field| field(index, sub_field));
+        }
+        self
+    }
+    pub(crate) fn into_errors(self) -> Vec<FieldError<F>> {
         let mut errors = Vec::new();
         for validation in self.validations.into_iter() {
-            if let Err(error) = validation.validator.validate() {
-                errors.push(FieldError {
-                    fields: validation.fields,
-                    error: validation
-                        .error_message
-                        .map(|m| ValidationError::Custom(m))
-                        .unwrap_or(error),
-                });
+            match validation {
+                Validation::Field(validation) => {
+                    let severity = validation.severity;
+                    let field_error = |error: ValidationError| FieldError {
+                        fields: validation.fields.iter().copied().collect(),
+                        primary: *validation.fields.first().expect("No fields on FieldError"),
+                        error: match severity {
+                            Some(severity) => error.with_severity(severity),
+                            None => error,
+                        },
+                    };
+                    match (validation.validator.validate(), validation.error_message) {
+                        (Ok(_), _) => {}
+                        (Err(error), Some(message)) => {
+                            errors.push(field_error(error.with_message(message)))
+                        }
+                        // Each error collected by `all()` or `any()` is reported separately
+                        (Err(ValidationError::Multiple(collected)), None) => {
+                            errors.extend(collected.into_iter().map(field_error))
+                        }
+                        (Err(error), None) => errors.push(field_error(error)),
+                    }
+                }
+                Validation::Nested(validate) => errors.extend(validate()),
             }
         }
-
-        if !errors.is_empty() {
-            Some(Rc::new(ErrorSet { errors }))
-        } else {
-            None
-        }
+        errors
+    }
+    pub fn validate(self) -> Option<Rc<ErrorSet<F>>> {
+        ErrorSet::from_errors(self.into_errors())
     }
 }
 
 pub mod prelude {
+    pub use super::asynchronous::{AsyncValidation, AsyncValidationStatus, AsyncValidator};
     pub use super::combinators::*;
+    pub use super::comparison::*;
+    pub use super::files::*;
+    pub use super::format::*;
+    pub use super::length::*;
+    pub use super::pattern::*;
     pub use super::present::*;
+    pub use super::range::*;
     pub use super::{
-        ErrorSet, FieldError, ModelValidator, Validatable, ValidationError, Validator,
-        ValidatorCombinators,
+        CustomError, ErrorSet, FieldError, ModelValidator, Severity, Validatable, ValidationError,
+        Validator, ValidatorCombinators,
     };
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{length::ValidatableLength, *};
+
+    #[test]
+    fn codes_and_params() {
+        let error = ValidationError::TooShort { min: 3, actual: 1 };
+        assert_eq!(error.code(), "too_short");
+        let params = error.params();
+        assert_eq!(params.get("min").map(String::as_str), Some("3"));
+        assert_eq!(params.get("actual").map(String::as_str), Some("1"));
+        assert!(ValidationError::NotPresent.params().is_empty());
+
+        let error = ValidationError::from(
+            CustomError::new("reserved", "{name} is reserved for {owner}")
+                .with_param("name", "admin")
+                .with_param("owner", "staff"),
+        );
+        assert_eq!(error.code(), "reserved");
+        assert_eq!(error.to_string(), "admin is reserved for staff");
+        assert_eq!(error.severity(), Severity::Error);
+    }
+
+    #[derive(Debug)]
+    struct Failing(ValidationError);
+
+    impl Validator for Failing {
+        fn validate(&self) -> Result<(), ValidationError> {
+            Err(self.0.clone())
+        }
+    }
+
+    #[test]
+    fn severities() {
+        let warning = CustomError::new("weak", "is weak").with_severity(Severity::Warning);
+        let errors = ModelValidator::default()
+            .with_field("password", Failing(warning.into()))
+            .with_warning("nickname", Some(String::from("jon")).is_absent())
+            .validate()
+            .unwrap();
+        assert!(!errors.is_blocking());
+        assert!(errors
+            .errors()
+            .iter()
+            .all(|error| error.severity() == Severity::Warning));
+        assert_eq!(errors.translate(|error| error.to_string()).len(), 2);
+        assert!(errors
+            .translate_severity(Severity::Error, |error| error.to_string())
+            .is_empty());
+        assert_eq!(
+            errors
+                .translate_severity(Severity::Warning, |error| error.to_string())
+                .len(),
+            2
+        );
+
+        let errors = ModelValidator::default()
+            .with_warning("nickname", Some(String::from("jon")).is_absent())
+            .with_field("name", None::<String>.is_present())
+            .validate()
+            .unwrap();
+        assert!(errors.is_blocking());
+        assert_eq!(
+            errors
+                .translate_severity(Severity::Error, |error| error.to_string())
+                .len(),
+            1
+        );
+        let warning = &errors.errors()[0];
+        assert_eq!(warning.code(), "not_absent");
+        assert_eq!(warning.error.to_string(), "should be blank");
+    }
+
+    #[test]
+    fn replaced_messages() {
+        let name = String::from("j");
+        let errors = ModelValidator::default()
+            .with_fields(
+                vec!["first_name", "last_name"].into_iter(),
+                name.has_length(2..),
+                "must have at least {min} characters",
+            )
+            .validate()
+            .unwrap();
+        let error = &errors.errors()[0];
+        assert_eq!(error.code(), "too_short");
+        assert_eq!(error.params().get("min").map(String::as_str), Some("2"));
+        assert_eq!(error.severity(), Severity::Error);
+        assert_eq!(error.error.to_string(), "must have at least 2 characters");
+    }
+}
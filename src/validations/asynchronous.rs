@@ -0,0 +1,306 @@
+use std::{
+    cell::RefCell,
+    rc::{Rc, Weak},
+    time::Duration,
+};
+
+use yew::{
+    services::timeout::{TimeoutService, TimeoutTask},
+    Callback,
+};
+
+use super::{ValidationError, Validator};
+use crate::forms::storage::{FormStorage, SubscriptionHandle};
+
+/// A validation that completes later, such as checking with a server whether a username is taken.
+pub trait AsyncValidator: std::fmt::Debug {
+    /// Starts validating, invoking `completed` with the result once known.
+    fn validate_async(&self, completed: Callback<Result<(), ValidationError>>);
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum AsyncValidationStatus {
+    Unchecked,
+    Pending,
+    Valid,
+    Invalid(ValidationError),
+}
+
+/// Runs an `AsyncValidator` and keeps its latest result.
+///
+/// Each run supersedes the previous one: results of earlier runs that complete afterwards are
+/// discarded. `check()` and watched storages wait for the debounce period to pass without further
+/// changes before running.
+#[derive(Clone)]
+pub struct AsyncValidation {
+    state: Rc<RefCell<AsyncValidationState>>,
+}
+
+struct AsyncValidationState {
+    validator: Rc<dyn AsyncValidator>,
+    status: AsyncValidationStatus,
+    generation: usize,
+    debounce: Duration,
+    debounce_task: Option<TimeoutTask>,
+    subscribers: Vec<(usize, Callback<AsyncValidationStatus>)>,
+    last_subscriber_id: usize,
+    watched: Vec<SubscriptionHandle>,
+}
+
+impl std::fmt::Debug for AsyncValidation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let state = self.state.borrow();
+        f.debug_struct("AsyncValidation")
+            .field("validator", &state.validator)
+            .field("status", &state.status)
+            .finish()
+    }
+}
+
+impl AsyncValidation {
+    pub fn new<V: AsyncValidator + 'static>(validator: V) -> Self {
+        Self {
+            state: Rc::new(RefCell::new(AsyncValidationState {
+                validator: Rc::new(validator),
+                status: AsyncValidationStatus::Unchecked,
+                generation: 0,
+                debounce: Duration::from_millis(300),
+                debounce_task: None,
+                subscribers: Vec::new(),
+                last_subscriber_id: 0,
+                watched: Vec::new(),
+            })),
+        }
+    }
+
+    pub fn with_debounce(self, debounce: Duration) -> Self {
+        self.state.borrow_mut().debounce = debounce;
+        self
+    }
+
+    /// Checks again whenever the value of `storage` changes.
+    pub fn watch<T>(&self, storage: &FormStorage<T>)
+    where
+        T: std::fmt::Debug + Default + Clone + PartialEq + 'static,
+    {
+        // The validator usually holds on to the storage, so only a weak reference can be captured here
+        let state = Rc::downgrade(&self.state);
+        let subscription = storage.subscribe(Callback::from(move |_| {
+            if let Some(validation) = Self::upgrade(&state) {
+                validation.check();
+            }
+        }));
+        self.state.borrow_mut().watched.push(subscription);
+    }
+
+    /// Invokes `callback` each time the status changes, until the returned handle is dropped.
+    pub fn subscribe(&self, callback: Callback<AsyncValidationStatus>) -> SubscriptionHandle {
+        let id = {
+            let mut state = self.state.borrow_mut();
+            state.last_subscriber_id += 1;
+            let id = state.last_subscriber_id;
+            state.subscribers.push((id, callback));
+            id
+        };
+        let state = Rc::downgrade(&self.state);
+        SubscriptionHandle::new(move || {
+            if let Some(state) = state.upgrade() {
+                state
+                    .borrow_mut()
+                    .subscribers
+                    .retain(|(subscriber_id, _)| *subscriber_id != id);
+            }
+        })
+    }
+
+    pub fn status(&self) -> AsyncValidationStatus {
+        self.state.borrow().status.clone()
+    }
+
+    pub fn is_pending(&self) -> bool {
+        self.status() == AsyncValidationStatus::Pending
+    }
+
+    /// Runs the validator once the debounce period passes without another call to `check()`.
+    pub fn check(&self) {
+        let generation = self.start();
+        let state = Rc::downgrade(&self.state);
+        let debounce = self.state.borrow().debounce;
+        let task = TimeoutService::spawn(
+            debounce,
+            Callback::from(move |_| {
+                if let Some(validation) = Self::upgrade(&state) {
+                    validation.run_generation(generation);
+                }
+            }),
+        );
+        self.state.borrow_mut().debounce_task = Some(task);
+    }
+
+    /// Runs the validator immediately.
+    pub fn run(&self) {
+        self.state.borrow_mut().debounce_task = None;
+        let generation = self.start();
+        self.run_generation(generation);
+    }
+
+    fn upgrade(state: &Weak<RefCell<AsyncValidationState>>) -> Option<Self> {
+        state.upgrade().map(|state| Self { state })
+    }
+
+    /// Marks the validation as pending, invalidating any run in progress.
+    fn start(&self) -> usize {
+        let generation = {
+            let mut state = self.state.borrow_mut();
+            state.generation += 1;
+            state.generation
+        };
+        self.set_status(AsyncValidationStatus::Pending);
+        generation
+    }
+
+    fn run_generation(&self, generation: usize) {
+        let state = Rc::downgrade(&self.state);
+        let completed = Callback::from(move |result: Result<(), ValidationError>| {
+            if let Some(validation) = Self::upgrade(&state) {
+                if validation.state.borrow().generation != generation {
+                    // A newer run has started since, so this result is stale
+                    return;
+                }
+                validation.set_status(match result {
+                    Ok(_) => AsyncValidationStatus::Valid,
+                    Err(error) => AsyncValidationStatus::Invalid(error),
+                });
+            }
+        });
+        // The validator may complete immediately, so the state must not be borrowed while it runs
+        let validator = self.state.borrow().validator.clone();
+        validator.validate_async(completed);
+    }
+
+    fn set_status(&self, status: AsyncValidationStatus) {
+        let subscribers = {
+            let mut state = self.state.borrow_mut();
+            if state.status == status {
+                return;
+            }
+            state.status = status.clone();
+            state
+                .subscribers
+                .iter()
+                .map(|(_, callback)| callback.clone())
+                .collect::<Vec<_>>()
+        };
+        for subscriber in subscribers {
+            subscriber.emit(status.clone());
+        }
+    }
+}
+
+/// Reports the latest result. A validation that has never run is started, and is reported as
+/// `ValidationError::Pending` until it completes.
+impl Validator for AsyncValidation {
+    fn validate(&self) -> Result<(), ValidationError> {
+        match self.status() {
+            AsyncValidationStatus::Unchecked => {
+                self.run();
+                match self.status() {
+                    AsyncValidationStatus::Pending | AsyncValidationStatus::Unchecked => {
+                        Err(ValidationError::Pending)
+                    }
+                    _ => self.validate(),
+                }
+            }
+            AsyncValidationStatus::Pending => Err(ValidationError::Pending),
+            AsyncValidationStatus::Valid => Ok(()),
+            AsyncValidationStatus::Invalid(error) => Err(error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validations::ModelValidator;
+
+    type Completion = Callback<Result<(), ValidationError>>;
+
+    #[derive(Debug, Default, Clone)]
+    struct ManualValidator {
+        requests: Rc<RefCell<Vec<Completion>>>,
+    }
+
+    impl AsyncValidator for ManualValidator {
+        fn validate_async(&self, completed: Callback<Result<(), ValidationError>>) {
+            self.requests.borrow_mut().push(completed);
+        }
+    }
+
+    #[test]
+    fn stale_results() {
+        let validator = ManualValidator::default();
+        let validation = AsyncValidation::new(validator.clone());
+        let statuses = Rc::new(RefCell::new(Vec::new()));
+        let _subscription = validation.subscribe({
+            let statuses = statuses.clone();
+            Callback::from(move |status| statuses.borrow_mut().push(status))
+        });
+        assert_eq!(validation.status(), AsyncValidationStatus::Unchecked);
+
+        validation.run();
+        validation.run();
+        assert!(validation.is_pending());
+        let requests = validator.requests.borrow().clone();
+        requests[0].emit(Err(ValidationError::custom("taken", "is taken")));
+        assert!(validation.is_pending());
+        requests[1].emit(Ok(()));
+        assert_eq!(validation.status(), AsyncValidationStatus::Valid);
+        assert_eq!(
+            statuses.borrow().as_slice(),
+            &[AsyncValidationStatus::Pending, AsyncValidationStatus::Valid]
+        );
+
+        validation.run();
+        validator.requests.borrow()[2].emit(Err(ValidationError::custom("taken", "is taken")));
+        assert_eq!(
+            validation.validate(),
+            Err(ValidationError::custom("taken", "is taken"))
+        );
+    }
+
+    #[derive(Debug)]
+    struct Immediate(bool);
+
+    impl AsyncValidator for Immediate {
+        fn validate_async(&self, completed: Callback<Result<(), ValidationError>>) {
+            completed.emit(if self.0 {
+                Ok(())
+            } else {
+                Err(ValidationError::custom("taken", "is taken"))
+            });
+        }
+    }
+
+    #[test]
+    fn model_validation() {
+        let pending = AsyncValidation::new(ManualValidator::default());
+        let errors = ModelValidator::default()
+            .with_async("username", &pending)
+            .with_async("email", &AsyncValidation::new(Immediate(true)))
+            .with_async("nickname", &AsyncValidation::new(Immediate(false)))
+            .validate()
+            .unwrap();
+        let errors = errors
+            .errors()
+            .iter()
+            .map(|error| (error.primary_field(), error.error.clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            errors,
+            vec![
+                ("username", ValidationError::Pending),
+                ("nickname", ValidationError::custom("taken", "is taken"))
+            ]
+        );
+    }
+}
@@ -1,4 +1,5 @@
-use super::{ValidationError, Validator};
+use super::{ValidatableStorage, ValidationError, Validator, ValidatorCombinators};
+use crate::forms::storage::FormStorage;
 
 #[derive(Debug)]
 pub struct AndValidation<T, U>
@@ -43,9 +44,131 @@ where
     }
 }
 
+#[derive(Debug)]
+pub struct NotValidation<T>
+where
+    T: std::fmt::Debug,
+{
+    pub validator: T,
+}
+
+impl<T> Validator for NotValidation<T>
+where
+    T: Validator + std::fmt::Debug,
+{
+    fn validate(&self) -> Result<(), ValidationError> {
+        match self.validator.validate() {
+            Ok(_) => Err(ValidationError::NotAllowed),
+            Err(_) => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct WhenValidation<T>
+where
+    T: std::fmt::Debug,
+{
+    pub condition: FormStorage<bool>,
+    pub validator: T,
+}
+
+impl<T> Validator for WhenValidation<T>
+where
+    T: Validator + std::fmt::Debug,
+{
+    fn validate(&self) -> Result<(), ValidationError> {
+        if self.condition.unchecked_value() {
+            self.validator.validate()
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Validates that `validator` passes while `condition` is true.
+pub fn when<T: Validator, C: ValidatableStorage<bool>>(
+    condition: &C,
+    validator: T,
+) -> WhenValidation<T> {
+    validator.when(condition)
+}
+
+/// Succeeds if every validator succeeds. Unlike `and()`, every validator is run, and every error is reported.
+pub fn all<I: IntoIterator<Item = Box<dyn Validator>>>(validators: I) -> AllValidation {
+    AllValidation {
+        validators: validators.into_iter().collect(),
+    }
+}
+
+/// Succeeds if any validator succeeds, or if there are no validators. Otherwise, every error is reported.
+pub fn any<I: IntoIterator<Item = Box<dyn Validator>>>(validators: I) -> AnyValidation {
+    AnyValidation {
+        validators: validators.into_iter().collect(),
+    }
+}
+
+#[derive(Debug)]
+pub struct AllValidation {
+    pub validators: Vec<Box<dyn Validator>>,
+}
+
+impl Validator for AllValidation {
+    fn validate(&self) -> Result<(), ValidationError> {
+        collect_errors(&self.validators).map_or(Ok(()), Err)
+    }
+}
+
+#[derive(Debug)]
+pub struct AnyValidation {
+    pub validators: Vec<Box<dyn Validator>>,
+}
+
+impl Validator for AnyValidation {
+    fn validate(&self) -> Result<(), ValidationError> {
+        // Each validator runs at most once, as some start work when validated, such as `AsyncValidation`
+        let mut errors = Vec::new();
+        for validator in self.validators.iter() {
+            match validator.validate() {
+                Ok(_) => return Ok(()),
+                Err(error) => push_error(&mut errors, error),
+            }
+        }
+        combine_errors(errors).map_or(Ok(()), Err)
+    }
+}
+
+/// Runs every validator, combining their errors into one.
+fn collect_errors(validators: &[Box<dyn Validator>]) -> Option<ValidationError> {
+    let mut errors = Vec::new();
+    for validator in validators {
+        if let Err(error) = validator.validate() {
+            push_error(&mut errors, error);
+        }
+    }
+    combine_errors(errors)
+}
+
+/// Adds `error` to `errors`, flattening nested `Multiple` errors.
+fn push_error(errors: &mut Vec<ValidationError>, error: ValidationError) {
+    match error {
+        ValidationError::Multiple(collected) => errors.extend(collected),
+        error => errors.push(error),
+    }
+}
+
+fn combine_errors(mut errors: Vec<ValidationError>) -> Option<ValidationError> {
+    match errors.len() {
+        0 => None,
+        1 => errors.pop(),
+        _ => Some(ValidationError::Multiple(errors)),
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::validations::prelude::*;
+    use crate::{forms::storage::FormStorage, validations::prelude::*};
+    use std::{cell::Cell, rc::Rc};
 
     #[test]
     fn test_combinators() {
@@ -100,4 +223,110 @@ mod tests {
             .validate()
             .expect_err("false and false = false");
     }
+
+    #[test]
+    fn negation() {
+        1.is_present()
+            .not()
+            .validate()
+            .expect_err("not true = false");
+        assert_eq!(
+            0.is_absent().not().validate(),
+            Err(ValidationError::NotAllowed)
+        );
+        1.is_absent().not().validate().expect("not false = true");
+    }
+
+    #[test]
+    fn conditions() {
+        let ticked = FormStorage::new(false);
+        let name = FormStorage::new(String::new());
+        name.is_present()
+            .when(&ticked)
+            .validate()
+            .expect("not validated while unticked");
+        ticked.clone().update(true);
+        assert_eq!(
+            when(&ticked, name.is_present()).validate(),
+            Err(ValidationError::NotPresent)
+        );
+    }
+
+    #[test]
+    fn all_and_any() {
+        let name = String::new();
+        all(vec![]).validate().expect("nothing to fail");
+        all(vec![1.is_present().boxed(), name.has_length(..=3).boxed()])
+            .validate()
+            .expect("all pass");
+        assert_eq!(
+            all(vec![
+                name.is_present().boxed(),
+                1.is_present().boxed(),
+                all(vec![name.has_length(1..).boxed(), 1.is_absent().boxed()]).boxed(),
+            ])
+            .validate(),
+            Err(ValidationError::Multiple(vec![
+                ValidationError::NotPresent,
+                ValidationError::TooShort { min: 1, actual: 0 },
+                ValidationError::NotAbsent,
+            ]))
+        );
+
+        any(vec![name.is_present().boxed(), name.is_absent().boxed()])
+            .validate()
+            .expect("one passes");
+        assert_eq!(
+            any(vec![name.is_present().boxed()]).validate(),
+            Err(ValidationError::NotPresent)
+        );
+
+        let errors = ModelValidator::default()
+            .with_field(
+                "name",
+                all(vec![
+                    name.is_present().boxed(),
+                    name.has_length(2..).boxed(),
+                ]),
+            )
+            .validate()
+            .unwrap();
+        assert_eq!(errors.errors().len(), 2);
+
+        name.is_present()
+            .all_of(vec![name.has_length(2..).boxed()])
+            .validate()
+            .expect_err("neither passes");
+        name.is_present()
+            .any_of(vec![name.is_absent().boxed()])
+            .validate()
+            .expect("the second passes");
+    }
+
+    #[derive(Debug, Default)]
+    struct Counting {
+        runs: Rc<Cell<usize>>,
+    }
+
+    impl Validator for Counting {
+        fn validate(&self) -> Result<(), ValidationError> {
+            self.runs.set(self.runs.get() + 1);
+            Err(ValidationError::NotAllowed)
+        }
+    }
+
+    #[test]
+    fn any_runs_each_validator_once() {
+        let first = Counting::default();
+        let second = Counting::default();
+        let runs = (first.runs.clone(), second.runs.clone());
+        assert_eq!(
+            first.any_of(vec![second.boxed()]).validate(),
+            Err(ValidationError::Multiple(vec![
+                ValidationError::NotAllowed,
+                ValidationError::NotAllowed,
+            ]))
+        );
+        assert_eq!((runs.0.get(), runs.1.get()), (1, 1));
+    }
 }
@@ -0,0 +1,212 @@
+use super::{ValidatableStorage, ValidationError, Validator};
+use crate::forms::storage::FormStorage;
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+
+/// A value that can be ordered against another value of the same type.
+pub trait Comparable: Default + Clone + PartialEq + std::fmt::Debug {
+    type Value: PartialOrd;
+
+    /// Returns None when there is no value to compare.
+    fn comparable(&self) -> Option<&Self::Value>;
+}
+
+macro_rules! impl_comparable {
+    ($($t:ty),*) => {
+        $(
+            impl Comparable for $t {
+                type Value = Self;
+
+                fn comparable(&self) -> Option<&Self::Value> {
+                    Some(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_comparable!(
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+    f32,
+    f64,
+    String,
+    NaiveDate,
+    NaiveTime,
+    NaiveDateTime,
+    DateTime<Utc>
+);
+
+impl<T> Comparable for Option<T>
+where
+    T: Comparable,
+{
+    type Value = T::Value;
+
+    fn comparable(&self) -> Option<&Self::Value> {
+        self.as_ref().and_then(|value| value.comparable())
+    }
+}
+
+/// Validations comparing a value with another storage, such as a password confirmation. Use
+/// `ModelValidator::with_compared` so that errors are reported on both fields.
+pub trait ValidatableComparison<T>
+where
+    T: Default + Clone + PartialEq + std::fmt::Debug,
+{
+    fn equals<O: ValidatableStorage<T>>(&self, other: &O) -> EqualityValidation<T>;
+    fn differs_from<O: ValidatableStorage<T>>(&self, other: &O) -> EqualityValidation<T>;
+    /// Validates that this value is greater than `other`. Absent values are not compared.
+    fn greater_than<O: ValidatableStorage<T>>(&self, other: &O) -> GreaterThanValidation<T>
+    where
+        T: Comparable;
+}
+
+impl<T, S> ValidatableComparison<T> for S
+where
+    T: Default + Clone + PartialEq + std::fmt::Debug,
+    S: ValidatableStorage<T>,
+{
+    fn equals<O: ValidatableStorage<T>>(&self, other: &O) -> EqualityValidation<T> {
+        EqualityValidation {
+            value: self.as_form_storage(),
+            other: other.as_form_storage(),
+            equal: true,
+        }
+    }
+
+    fn differs_from<O: ValidatableStorage<T>>(&self, other: &O) -> EqualityValidation<T> {
+        EqualityValidation {
+            value: self.as_form_storage(),
+            other: other.as_form_storage(),
+            equal: false,
+        }
+    }
+
+    fn greater_than<O: ValidatableStorage<T>>(&self, other: &O) -> GreaterThanValidation<T>
+    where
+        T: Comparable,
+    {
+        GreaterThanValidation {
+            value: self.as_form_storage(),
+            other: other.as_form_storage(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct EqualityValidation<T>
+where
+    T: Default + Clone + PartialEq + std::fmt::Debug,
+{
+    pub value: FormStorage<T>,
+    pub other: FormStorage<T>,
+    /// Whether the values should be equal or differ.
+    pub equal: bool,
+}
+
+impl<T> Validator for EqualityValidation<T>
+where
+    T: Default + Clone + PartialEq + std::fmt::Debug,
+{
+    fn validate(&self) -> Result<(), ValidationError> {
+        let equal = self.value.value()? == self.other.value()?;
+        match (self.equal, equal) {
+            (true, false) => Err(ValidationError::Mismatch),
+            (false, true) => Err(ValidationError::NotDistinct),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct GreaterThanValidation<T>
+where
+    T: Default + Clone + PartialEq + std::fmt::Debug,
+{
+    pub value: FormStorage<T>,
+    pub other: FormStorage<T>,
+}
+
+impl<T> Validator for GreaterThanValidation<T>
+where
+    T: Comparable,
+{
+    fn validate(&self) -> Result<(), ValidationError> {
+        let value = self.value.value()?;
+        let other = self.other.value()?;
+        match (value.comparable(), other.comparable()) {
+            (Some(value), Some(other)) if value <= other => Err(ValidationError::NotGreater),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{forms::storage::FormStorage, validations::prelude::*};
+    use chrono::NaiveDate;
+    use std::collections::HashSet;
+
+    #[test]
+    fn comparisons() {
+        let password = FormStorage::new(String::from("hunter2"));
+        let confirmation = FormStorage::new(String::from("hunter2"));
+        confirmation.equals(&password).validate().expect("equal");
+        assert_eq!(
+            confirmation.differs_from(&password).validate(),
+            Err(ValidationError::NotDistinct)
+        );
+        confirmation.clone().update(String::from("hunter3"));
+        assert_eq!(
+            confirmation.equals(&password).validate(),
+            Err(ValidationError::Mismatch)
+        );
+
+        let start = FormStorage::new(NaiveDate::from_ymd_opt(2020, 1, 1));
+        let end = FormStorage::new(None);
+        end.greater_than(&start)
+            .validate()
+            .expect("absent values are not compared");
+        end.clone().update(NaiveDate::from_ymd_opt(2020, 1, 1));
+        assert_eq!(
+            end.greater_than(&start).validate(),
+            Err(ValidationError::NotGreater)
+        );
+        end.clone().update(NaiveDate::from_ymd_opt(2020, 1, 2));
+        end.greater_than(&start).validate().expect("later date");
+        2.5.greater_than(&2.).validate().expect("greater float");
+    }
+
+    #[test]
+    fn errors_on_both_fields() {
+        let password = FormStorage::new(String::from("hunter2"));
+        let confirmation = FormStorage::new(String::from("hunter3"));
+        let errors = ModelValidator::default()
+            .with_compared(
+                "password_confirmation",
+                "password",
+                confirmation.equals(&password),
+            )
+            .validate()
+            .unwrap();
+        let translated = errors.translate(|error| error.error.to_string());
+        assert_eq!(
+            translated.keys().copied().collect::<HashSet<_>>(),
+            vec!["password", "password_confirmation"]
+                .into_iter()
+                .collect()
+        );
+        // The first field is focused when the form is submitted
+        assert_eq!(errors.errors()[0].primary_field(), "password_confirmation");
+    }
+}
@@ -0,0 +1,34 @@
+use super::{ValidationError, Validator};
+use crate::forms::{file::FileHandle, storage::FormStorage};
+
+pub trait ValidatableFiles {
+    fn are_files_accepted(&self) -> FilesAcceptedValidation;
+}
+
+impl ValidatableFiles for FormStorage<Vec<FileHandle>> {
+    fn are_files_accepted(&self) -> FilesAcceptedValidation {
+        FilesAcceptedValidation {
+            value: self.clone(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct FilesAcceptedValidation {
+    pub value: FormStorage<Vec<FileHandle>>,
+}
+
+impl Validator for FilesAcceptedValidation {
+    fn validate(&self) -> Result<(), ValidationError> {
+        let rejected = self.value.unchecked_value().into_iter().find_map(|handle| {
+            handle
+                .rejection
+                .clone()
+                .map(|reason| (handle.name(), reason))
+        });
+        match rejected {
+            Some((name, reason)) => Err(ValidationError::FileRejected { name, reason }),
+            None => Ok(()),
+        }
+    }
+}
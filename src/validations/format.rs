@@ -0,0 +1,219 @@
+use super::{pattern::HasText, ValidatableStorage, ValidationError, Validator};
+use crate::forms::storage::FormStorage;
+use regex::Regex;
+use std::sync::OnceLock;
+
+/// A well-known text format.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum TextFormat {
+    /// A [valid email address](https://html.spec.whatwg.org/multipage/input.html#valid-e-mail-address) as
+    /// defined by HTML5, which is what `<input type="email">` accepts. Quoted local parts and IP address
+    /// domains are rejected.
+    Email,
+    /// An absolute `http` or `https` URL with a host, parsed following the
+    /// [WHATWG URL standard](https://url.spec.whatwg.org/). Other schemes, such as `javascript:`, are rejected.
+    Url,
+    /// A phone number in [E.164](https://en.wikipedia.org/wiki/E.164) format: a `+`, a non-zero country code
+    /// digit and at most 15 digits in total, without spaces or punctuation.
+    E164Phone,
+    /// A hyphenated UUID such as `67e55044-10b1-426f-9247-bb680e5fe0c8`, in either case.
+    Uuid,
+}
+
+impl TextFormat {
+    pub fn is_valid(self, text: &str) -> bool {
+        match self {
+            TextFormat::Email => email_regex().is_match(text),
+            TextFormat::Url => url::Url::parse(text)
+                .map(|url| matches!(url.scheme(), "http" | "https") && url.has_host())
+                .unwrap_or_default(),
+            TextFormat::E164Phone => phone_regex().is_match(text),
+            TextFormat::Uuid => uuid_regex().is_match(text),
+        }
+    }
+}
+
+impl std::fmt::Display for TextFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            TextFormat::Email => "email address",
+            TextFormat::Url => "URL",
+            TextFormat::E164Phone => "phone number",
+            TextFormat::Uuid => "UUID",
+        })
+    }
+}
+
+fn email_regex() -> &'static Regex {
+    static REGEX: OnceLock<Regex> = OnceLock::new();
+    REGEX.get_or_init(|| {
+        // Taken verbatim from the HTML5 specification
+        Regex::new(
+            r"^[a-zA-Z0-9.!#$%&'*+/=?^_`{|}~-]+@[a-zA-Z0-9](?:[a-zA-Z0-9-]{0,61}[a-zA-Z0-9])?(?:\.[a-zA-Z0-9](?:[a-zA-Z0-9-]{0,61}[a-zA-Z0-9])?)*$",
+        )
+        .unwrap()
+    })
+}
+
+fn phone_regex() -> &'static Regex {
+    static REGEX: OnceLock<Regex> = OnceLock::new();
+    REGEX.get_or_init(|| Regex::new(r"^\+[1-9][0-9]{1,14}$").unwrap())
+}
+
+fn uuid_regex() -> &'static Regex {
+    static REGEX: OnceLock<Regex> = OnceLock::new();
+    REGEX.get_or_init(|| {
+        Regex::new(r"^[0-9a-fA-F]{8}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{12}$")
+            .unwrap()
+    })
+}
+
+pub trait ValidatableFormat<T>
+where
+    T: HasText,
+{
+    fn has_format(&self, format: TextFormat) -> FormatValidation<T>;
+
+    fn is_email(&self) -> FormatValidation<T> {
+        self.has_format(TextFormat::Email)
+    }
+
+    fn is_url(&self) -> FormatValidation<T> {
+        self.has_format(TextFormat::Url)
+    }
+
+    fn is_e164_phone(&self) -> FormatValidation<T> {
+        self.has_format(TextFormat::E164Phone)
+    }
+
+    fn is_uuid(&self) -> FormatValidation<T> {
+        self.has_format(TextFormat::Uuid)
+    }
+}
+
+impl<T, S> ValidatableFormat<T> for S
+where
+    T: HasText,
+    S: ValidatableStorage<T>,
+{
+    fn has_format(&self, format: TextFormat) -> FormatValidation<T> {
+        FormatValidation {
+            value: self.as_form_storage(),
+            format,
+        }
+    }
+}
+
+/// Validates that text is in a `TextFormat`. Absent values, such as `None`, are not validated.
+#[derive(Debug)]
+pub struct FormatValidation<T>
+where
+    T: Default + Clone + PartialEq + std::fmt::Debug,
+{
+    pub value: FormStorage<T>,
+    pub format: TextFormat,
+}
+
+impl<T> Validator for FormatValidation<T>
+where
+    T: HasText,
+{
+    fn validate(&self) -> Result<(), ValidationError> {
+        match self.value.value()?.text() {
+            Some(text) if !self.format.is_valid(text) => Err(ValidationError::InvalidFormat {
+                format: self.format,
+            }),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::validations::prelude::*;
+
+    #[test]
+    fn formats() {
+        let table = [
+            (TextFormat::Email, "jon@khonsulabs.com", true),
+            (TextFormat::Email, "first.last+tag@sub.example.co", true),
+            (TextFormat::Email, "o'neil@example.com", true),
+            (TextFormat::Email, "user@localhost", true),
+            (TextFormat::Email, "", false),
+            (TextFormat::Email, "no-at-sign.example.com", false),
+            (TextFormat::Email, "two@@example.com", false),
+            (TextFormat::Email, "user@-example.com", false),
+            (TextFormat::Email, "user@example..com", false),
+            (TextFormat::Email, "\"quoted\"@example.com", false),
+            (TextFormat::Email, "user name@example.com", false),
+            (TextFormat::Url, "https://khonsulabs.com", true),
+            (
+                TextFormat::Url,
+                "http://example.com:8080/path?query=1#fragment",
+                true,
+            ),
+            (TextFormat::Url, "https://ünicode.example/", true),
+            (TextFormat::Url, "example.com", false),
+            (TextFormat::Url, "ftp://example.com", false),
+            (TextFormat::Url, "javascript:alert(1)", false),
+            (TextFormat::Url, "https://", false),
+            (TextFormat::E164Phone, "+14155552671", true),
+            (TextFormat::E164Phone, "+442071838750", true),
+            (TextFormat::E164Phone, "14155552671", false),
+            (TextFormat::E164Phone, "+1 415 555 2671", false),
+            (TextFormat::E164Phone, "+04155552671", false),
+            (TextFormat::E164Phone, "+1234567890123456", false),
+            (
+                TextFormat::Uuid,
+                "67e55044-10b1-426f-9247-bb680e5fe0c8",
+                true,
+            ),
+            (
+                TextFormat::Uuid,
+                "67E55044-10B1-426F-9247-BB680E5FE0C8",
+                true,
+            ),
+            (TextFormat::Uuid, "67e5504410b1426f9247bb680e5fe0c8", false),
+            (
+                TextFormat::Uuid,
+                "67e55044-10b1-426f-9247-bb680e5fe0c",
+                false,
+            ),
+            (
+                TextFormat::Uuid,
+                "g7e55044-10b1-426f-9247-bb680e5fe0c8",
+                false,
+            ),
+        ];
+        for (format, text, valid) in table.iter() {
+            assert_eq!(
+                String::from(*text).has_format(*format).validate().is_ok(),
+                *valid,
+                "{:?} {:?}",
+                format,
+                text
+            );
+        }
+    }
+
+    #[test]
+    fn optional_formats() {
+        Option::<String>::None
+            .is_email()
+            .validate()
+            .expect("absent values are not validated");
+        assert_eq!(
+            Some(String::from("nope")).is_uuid().validate(),
+            Err(ValidationError::InvalidFormat {
+                format: TextFormat::Uuid
+            })
+        );
+        assert_eq!(
+            ValidationError::InvalidFormat {
+                format: TextFormat::Email
+            }
+            .to_string(),
+            "is not a valid email address"
+        );
+    }
+}
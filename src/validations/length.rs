@@ -0,0 +1,202 @@
+use super::{ValidatableStorage, ValidationError, Validator};
+use crate::forms::storage::FormStorage;
+use std::ops::{Bound, RangeBounds};
+use unicode_segmentation::UnicodeSegmentation;
+
+/// How the length of text is counted.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub enum LengthUnit {
+    /// Unicode scalar values, as returned by `str::chars`.
+    #[default]
+    Scalars,
+    /// Extended grapheme clusters, which match what users perceive as characters. For example, "🇨🇦" is two
+    /// scalar values but a single grapheme.
+    Graphemes,
+}
+
+pub trait HasLength: Default + Clone + PartialEq + std::fmt::Debug {
+    /// Returns None when there is no value to measure.
+    fn length(&self) -> Option<usize> {
+        self.length_in(LengthUnit::default())
+    }
+
+    /// Returns the length counted in `unit`, which only applies to text. Returns None when there is no value to
+    /// measure.
+    fn length_in(&self, unit: LengthUnit) -> Option<usize>;
+}
+
+impl HasLength for String {
+    fn length_in(&self, unit: LengthUnit) -> Option<usize> {
+        Some(match unit {
+            LengthUnit::Scalars => self.chars().count(),
+            LengthUnit::Graphemes => self.graphemes(true).count(),
+        })
+    }
+}
+
+impl<T> HasLength for Vec<T>
+where
+    T: Clone + PartialEq + std::fmt::Debug,
+{
+    fn length_in(&self, _unit: LengthUnit) -> Option<usize> {
+        Some(self.len())
+    }
+}
+
+impl<T> HasLength for Option<T>
+where
+    T: HasLength,
+{
+    fn length_in(&self, unit: LengthUnit) -> Option<usize> {
+        self.as_ref().and_then(|value| value.length_in(unit))
+    }
+}
+
+pub trait ValidatableLength<T>
+where
+    T: HasLength,
+{
+    fn has_length<R: RangeBounds<usize>>(&self, range: R) -> LengthValidation<T>;
+}
+
+impl<T, S> ValidatableLength<T> for S
+where
+    T: HasLength,
+    S: ValidatableStorage<T>,
+{
+    fn has_length<R: RangeBounds<usize>>(&self, range: R) -> LengthValidation<T> {
+        LengthValidation {
+            value: self.as_form_storage(),
+            min: range.start_bound().cloned(),
+            max: range.end_bound().cloned(),
+            unit: LengthUnit::default(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct LengthValidation<T>
+where
+    T: Default + Clone + PartialEq + std::fmt::Debug,
+{
+    pub value: FormStorage<T>,
+    pub min: Bound<usize>,
+    pub max: Bound<usize>,
+    pub unit: LengthUnit,
+}
+
+impl<T> LengthValidation<T>
+where
+    T: HasLength,
+{
+    /// Counts the length in `unit` rather than in Unicode scalar values.
+    pub fn counting(mut self, unit: LengthUnit) -> Self {
+        self.unit = unit;
+        self
+    }
+}
+
+impl<T> Validator for LengthValidation<T>
+where
+    T: HasLength,
+{
+    fn validate(&self) -> Result<(), ValidationError> {
+        let actual = match self.value.value()?.length_in(self.unit) {
+            Some(length) => length,
+            None => return Ok(()),
+        };
+        // Excluded bounds are reported as the nearest included length. Nothing satisfies `..0` or
+        // `(Excluded(usize::MAX), _)`, so those are reported with the bound itself.
+        match self.min {
+            Bound::Included(min) if actual < min => {
+                return Err(ValidationError::TooShort { min, actual })
+            }
+            Bound::Excluded(min) if actual <= min => {
+                return Err(ValidationError::TooShort {
+                    min: min.saturating_add(1),
+                    actual,
+                })
+            }
+            _ => {}
+        }
+        match self.max {
+            Bound::Included(max) if actual > max => Err(ValidationError::TooLong { max, actual }),
+            Bound::Excluded(max) if actual >= max => Err(ValidationError::TooLong {
+                max: max.saturating_sub(1),
+                actual,
+            }),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::validations::prelude::*;
+    use std::ops::Bound;
+
+    #[test]
+    fn string_lengths() {
+        String::from("abc")
+            .has_length(1..=3)
+            .validate()
+            .expect("3 is within 1..=3");
+        String::from("abcd")
+            .has_length(1..=3)
+            .validate()
+            .expect_err("4 is not within 1..=3");
+        String::from("abc")
+            .has_length(1..3)
+            .validate()
+            .expect_err("3 is not within 1..3");
+        String::from("é")
+            .has_length(..=1)
+            .validate()
+            .expect("length counts characters rather than bytes");
+        String::from("🇨🇦")
+            .has_length(..=1)
+            .validate()
+            .expect_err("a flag is two scalar values");
+        String::from("🇨🇦")
+            .has_length(..=1)
+            .counting(LengthUnit::Graphemes)
+            .validate()
+            .expect("a flag is a single grapheme");
+        assert_eq!(
+            String::from("éé")
+                .has_length(3..)
+                .counting(LengthUnit::Graphemes)
+                .validate(),
+            Err(ValidationError::TooShort { min: 3, actual: 2 })
+        );
+        String::new()
+            .has_length(1..)
+            .validate()
+            .expect_err("0 is not within 1..");
+        String::new()
+            .has_length(..0)
+            .validate()
+            .expect_err("nothing is within ..0");
+        assert_eq!(
+            String::from("abc")
+                .has_length((Bound::Excluded(usize::MAX), Bound::Unbounded))
+                .validate(),
+            Err(ValidationError::TooShort {
+                min: usize::MAX,
+                actual: 3
+            })
+        );
+    }
+
+    #[test]
+    fn optional_lengths() {
+        Option::<String>::None
+            .has_length(1..)
+            .validate()
+            .expect("absent values are not measured");
+        Some(String::new())
+            .has_length(1..)
+            .validate()
+            .expect_err("present values are measured");
+    }
+}
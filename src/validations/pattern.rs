@@ -0,0 +1,148 @@
+use super::{ValidatableStorage, ValidationError, Validator};
+use crate::forms::storage::FormStorage;
+use regex::Regex;
+
+/// A value that can be matched against a pattern.
+pub trait HasText: Default + Clone + PartialEq + std::fmt::Debug {
+    /// Returns None when there is no text to match.
+    fn text(&self) -> Option<&str>;
+}
+
+impl HasText for String {
+    fn text(&self) -> Option<&str> {
+        Some(self)
+    }
+}
+
+impl<T> HasText for Option<T>
+where
+    T: HasText,
+{
+    fn text(&self) -> Option<&str> {
+        self.as_ref().and_then(|value| value.text())
+    }
+}
+
+/// Compiles `pattern` so that it only matches whole values, for use with `ValidatablePattern::matches_regex`.
+pub fn anchored_regex(pattern: &str) -> Result<Regex, regex::Error> {
+    // Anchoring the pattern prevents it from matching only part of the value
+    Regex::new(&format!("^(?:{})$", pattern))
+}
+
+pub trait ValidatablePattern<T>
+where
+    T: HasText,
+{
+    /// Validates that the whole value matches the regular expression `pattern`. Returns an error if `pattern` is
+    /// not a valid regular expression.
+    ///
+    /// The pattern is compiled on each call. Validators that run often should compile it once with
+    /// `anchored_regex` and use `matches_regex` instead.
+    fn matches(&self, pattern: &str) -> Result<PatternValidation<T>, regex::Error>;
+
+    /// Validates that the value matches `regex`. The regex is used as is, so it should be anchored to match whole
+    /// values.
+    fn matches_regex(&self, regex: &Regex) -> PatternValidation<T>;
+}
+
+impl<T, S> ValidatablePattern<T> for S
+where
+    T: HasText,
+    S: ValidatableStorage<T>,
+{
+    fn matches(&self, pattern: &str) -> Result<PatternValidation<T>, regex::Error> {
+        Ok(PatternValidation {
+            value: self.as_form_storage(),
+            pattern: pattern.to_owned(),
+            regex: anchored_regex(pattern)?,
+        })
+    }
+
+    fn matches_regex(&self, regex: &Regex) -> PatternValidation<T> {
+        PatternValidation {
+            value: self.as_form_storage(),
+            pattern: regex.as_str().to_owned(),
+            regex: regex.clone(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct PatternValidation<T>
+where
+    T: Default + Clone + PartialEq + std::fmt::Debug,
+{
+    pub value: FormStorage<T>,
+    pub pattern: String,
+    regex: Regex,
+}
+
+impl<T> Validator for PatternValidation<T>
+where
+    T: HasText,
+{
+    fn validate(&self) -> Result<(), ValidationError> {
+        match self.value.value()?.text() {
+            Some(text) if !self.regex.is_match(text) => Err(ValidationError::PatternMismatch {
+                pattern: self.pattern.clone(),
+            }),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::validations::prelude::*;
+
+    #[test]
+    fn patterns() {
+        String::from("AB-123")
+            .matches("[A-Z]{2}-[0-9]+")
+            .expect("valid pattern")
+            .validate()
+            .expect("matches");
+        assert_eq!(
+            String::from("xAB-123")
+                .matches("[A-Z]{2}-[0-9]+")
+                .expect("valid pattern")
+                .validate(),
+            Err(ValidationError::PatternMismatch {
+                pattern: String::from("[A-Z]{2}-[0-9]+")
+            })
+        );
+        String::from("a")
+            .matches("a|b")
+            .expect("valid pattern")
+            .validate()
+            .expect("alternations are anchored as a whole");
+        String::from("ab")
+            .matches("a|b")
+            .expect("valid pattern")
+            .validate()
+            .expect_err("alternations are anchored as a whole");
+        Option::<String>::None
+            .matches("[0-9]+")
+            .expect("valid pattern")
+            .validate()
+            .expect("absent values are not matched");
+    }
+
+    #[test]
+    fn compiled_patterns() {
+        String::new()
+            .matches("[0-9")
+            .expect_err("invalid patterns are reported");
+        let regex = anchored_regex("[0-9]+").expect("valid pattern");
+        String::from("42")
+            .matches_regex(&regex)
+            .validate()
+            .expect("matches");
+        assert_eq!(
+            String::from("x42").matches_regex(&regex).validate(),
+            Err(ValidationError::PatternMismatch {
+                pattern: String::from("^(?:[0-9]+)$")
+            })
+        );
+    }
+}
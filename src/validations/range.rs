@@ -0,0 +1,166 @@
+use super::{ValidatableStorage, ValidationError, Validator};
+use crate::forms::storage::FormStorage;
+use std::ops::{Bound, RangeBounds};
+
+/// A value that can be compared against the bounds of a range of `B`s.
+pub trait RangeValue<B>: Default + Clone + PartialEq + std::fmt::Debug {
+    /// Returns None when there is no value to compare.
+    fn range_value(&self) -> Option<&B>;
+}
+
+impl<T> RangeValue<T> for T
+where
+    T: PartialOrd + Default + Clone + std::fmt::Debug,
+{
+    fn range_value(&self) -> Option<&T> {
+        Some(self)
+    }
+}
+
+impl<T> RangeValue<T> for Option<T>
+where
+    T: PartialOrd + Clone + std::fmt::Debug,
+{
+    fn range_value(&self) -> Option<&T> {
+        self.as_ref()
+    }
+}
+
+/// Validates that a value lies within a range. Absent values, such as `None`, are not validated.
+pub trait ValidatableRange<T, B>
+where
+    T: RangeValue<B>,
+    B: PartialOrd + ToString + Clone,
+{
+    fn is_in_range<R: RangeBounds<B>>(&self, range: R) -> RangeValidation<T, B>;
+}
+
+impl<T, B, S> ValidatableRange<T, B> for S
+where
+    T: RangeValue<B>,
+    B: PartialOrd + ToString + Clone,
+    S: ValidatableStorage<T>,
+{
+    fn is_in_range<R: RangeBounds<B>>(&self, range: R) -> RangeValidation<T, B> {
+        RangeValidation {
+            value: self.as_form_storage(),
+            min: range.start_bound().cloned(),
+            max: range.end_bound().cloned(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct RangeValidation<T, B>
+where
+    T: RangeValue<B>,
+{
+    pub value: FormStorage<T>,
+    pub min: Bound<B>,
+    pub max: Bound<B>,
+}
+
+impl<T, B> Validator for RangeValidation<T, B>
+where
+    T: RangeValue<B>,
+    B: PartialOrd + ToString + std::fmt::Debug,
+{
+    fn validate(&self) -> Result<(), ValidationError> {
+        let value = self.value.value()?;
+        let actual = match value.range_value() {
+            Some(actual) => actual,
+            None => return Ok(()),
+        };
+        match &self.min {
+            Bound::Included(min) if actual < min => {
+                return Err(ValidationError::TooSmall {
+                    min: min.to_string(),
+                    inclusive: true,
+                    actual: actual.to_string(),
+                })
+            }
+            Bound::Excluded(min) if actual <= min => {
+                return Err(ValidationError::TooSmall {
+                    min: min.to_string(),
+                    inclusive: false,
+                    actual: actual.to_string(),
+                })
+            }
+            _ => {}
+        }
+        match &self.max {
+            Bound::Included(max) if actual > max => Err(ValidationError::TooLarge {
+                max: max.to_string(),
+                inclusive: true,
+                actual: actual.to_string(),
+            }),
+            Bound::Excluded(max) if actual >= max => Err(ValidationError::TooLarge {
+                max: max.to_string(),
+                inclusive: false,
+                actual: actual.to_string(),
+            }),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{forms::storage::FormStorage, validations::prelude::*};
+
+    #[test]
+    fn ranges() {
+        5.is_in_range(1..=5).validate().expect("5 is within 1..=5");
+        assert_eq!(
+            5.is_in_range(1..5).validate(),
+            Err(ValidationError::TooLarge {
+                max: String::from("5"),
+                inclusive: false,
+                actual: String::from("5"),
+            })
+        );
+        assert_eq!(
+            0.5.is_in_range(1.0..).validate(),
+            Err(ValidationError::TooSmall {
+                min: String::from("1"),
+                inclusive: true,
+                actual: String::from("0.5"),
+            })
+        );
+        assert_eq!(
+            ValidationError::TooSmall {
+                min: String::from("1"),
+                inclusive: false,
+                actual: String::from("0"),
+            }
+            .to_string(),
+            "must be greater than 1"
+        );
+        String::from("m")
+            .is_in_range(String::from("a")..String::from("n"))
+            .validate()
+            .expect("strings compare lexicographically");
+    }
+
+    #[test]
+    fn optional_values() {
+        let age = FormStorage::<Option<u32>>::default();
+        age.is_in_range(18..)
+            .validate()
+            .expect("absent values aren't validated");
+        age.clone().update(Some(16));
+        let start = chrono::NaiveDate::from_ymd_opt(2020, 1, 1).unwrap();
+        Some(start)
+            .is_in_range(start..)
+            .validate()
+            .expect("any PartialOrd value can be validated");
+        assert_eq!(
+            age.is_in_range(18..).validate(),
+            Err(ValidationError::TooSmall {
+                min: String::from("18"),
+                inclusive: true,
+                actual: String::from("16"),
+            })
+        );
+    }
+}
@@ -0,0 +1,258 @@
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{ext::IdentExt, parse_macro_input, Data, DeriveInput, Fields, Ident, LitInt};
+
+/// Generates a `FormField` enum, a storage struct holding a `FormStorage` per field and a
+/// `ModelValidator` built from `#[validate(...)]` attributes.
+///
+/// For a struct named `Profile`, this generates:
+///
+/// - `ProfileField`: an enum with a variant per field, implementing `FormField`.
+/// - `ProfileStorage`: a struct with a `FormStorage` per field, convertible from `&Profile` and back
+///   through `to_model()`, and providing `validator()`.
+///
+/// Supported validations are `present`, `absent` and `length(min = 1, max = 80)`.
+#[proc_macro_derive(BulmaForm, attributes(validate))]
+pub fn derive_bulma_form(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match expand(input) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+struct FormFieldInfo {
+    ident: Ident,
+    variant: Ident,
+    ty: syn::Type,
+    validations: Vec<Validation>,
+}
+
+enum Validation {
+    Present,
+    Absent,
+    Length {
+        min: Option<LitInt>,
+        max: Option<LitInt>,
+    },
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    if !input.generics.params.is_empty() || input.generics.where_clause.is_some() {
+        return Err(syn::Error::new_spanned(
+            &input.generics,
+            "BulmaForm can't be derived for generic structs",
+        ));
+    }
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &input.ident,
+                    "BulmaForm can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "BulmaForm can only be derived for structs",
+            ))
+        }
+    };
+
+    let fields = fields
+        .iter()
+        .map(|field| {
+            let ident = field.ident.clone().expect("named field");
+            let mut validations = Vec::new();
+            for attr in field.attrs.iter().filter(|a| a.path().is_ident("validate")) {
+                parse_validations(attr, &mut validations)?;
+            }
+            Ok(FormFieldInfo {
+                variant: Ident::new(&pascal_case(&ident.unraw().to_string()), ident.span()),
+                ident,
+                ty: field.ty.clone(),
+                validations,
+            })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    let vis = &input.vis;
+    let model = &input.ident;
+    let field_enum = format_ident!("{}Field", model);
+    let storage = format_ident!("{}Storage", model);
+    let id_prefix = snake_case(&model.to_string());
+
+    let variants = fields.iter().map(|f| &f.variant).collect::<Vec<_>>();
+    let idents = fields.iter().map(|f| &f.ident).collect::<Vec<_>>();
+    let types = fields.iter().map(|f| &f.ty).collect::<Vec<_>>();
+    let form_ids = fields
+        .iter()
+        .map(|f| format!("{}_{}", id_prefix, f.ident.unraw()))
+        .collect::<Vec<_>>();
+    let validators = fields.iter().filter_map(|f| {
+        let ident = &f.ident;
+        let variant = &f.variant;
+        let mut validations = f.validations.iter().map(|v| v.expand(ident));
+        let first = validations.next()?;
+        let combined = validations.fold(first, |combined, next| {
+            quote! { ::yew_bulma::validations::ValidatorCombinators::and(#combined, #next) }
+        });
+        Some(quote! {
+            .with_field(#field_enum::#variant, #combined)
+        })
+    });
+
+    Ok(quote! {
+        #[derive(Debug, Clone, Copy, Hash, Eq, PartialEq)]
+        #vis enum #field_enum {
+            #( #variants, )*
+        }
+
+        impl ::yew_bulma::forms::FormField for #field_enum {
+            fn form_id(&self) -> ::std::borrow::Cow<'static, str> {
+                match self {
+                    #( #field_enum::#variants => ::std::borrow::Cow::Borrowed(#form_ids), )*
+                }
+            }
+        }
+
+        #[derive(Debug, Default, Clone)]
+        #vis struct #storage {
+            #( pub #idents: ::yew_bulma::forms::storage::FormStorage<#types>, )*
+        }
+
+        impl #storage {
+            pub fn new(model: &#model) -> Self {
+                Self {
+                    #( #idents: ::yew_bulma::forms::storage::FormStorage::new(model.#idents.clone()), )*
+                }
+            }
+
+            pub fn to_model(&self) -> ::std::result::Result<#model, ::yew_bulma::validations::ValidationError> {
+                Ok(#model {
+                    #( #idents: self.#idents.value()?, )*
+                })
+            }
+
+            pub fn validator(&self) -> ::yew_bulma::validations::ModelValidator<#field_enum> {
+                ::yew_bulma::validations::ModelValidator::default()
+                    #( #validators )*
+            }
+        }
+
+        impl ::std::convert::From<&#model> for #storage {
+            fn from(model: &#model) -> Self {
+                Self::new(model)
+            }
+        }
+    })
+}
+
+fn parse_validations(attr: &syn::Attribute, validations: &mut Vec<Validation>) -> syn::Result<()> {
+    attr.parse_nested_meta(|meta| {
+        if meta.path.is_ident("present") {
+            validations.push(Validation::Present);
+            Ok(())
+        } else if meta.path.is_ident("absent") {
+            validations.push(Validation::Absent);
+            Ok(())
+        } else if meta.path.is_ident("length") {
+            let mut min = None;
+            let mut max = None;
+            meta.parse_nested_meta(|bound| {
+                if bound.path.is_ident("min") {
+                    min = Some(bound.value()?.parse()?);
+                    Ok(())
+                } else if bound.path.is_ident("max") {
+                    max = Some(bound.value()?.parse()?);
+                    Ok(())
+                } else {
+                    Err(bound.error("expected `min` or `max`"))
+                }
+            })?;
+            if min.is_none() && max.is_none() {
+                return Err(meta.error("length requires `min`, `max` or both"));
+            }
+            validations.push(Validation::Length { min, max });
+            Ok(())
+        } else {
+            Err(meta.error("unsupported validation, expected `present`, `absent` or `length`"))
+        }
+    })
+}
+
+impl Validation {
+    fn expand(&self, field: &Ident) -> TokenStream2 {
+        match self {
+            Validation::Present => {
+                quote! { ::yew_bulma::validations::Validatable::is_present(&self.#field) }
+            }
+            Validation::Absent => {
+                quote! { ::yew_bulma::validations::Validatable::is_absent(&self.#field) }
+            }
+            Validation::Length { min, max } => {
+                let range = match (min, max) {
+                    (Some(min), Some(max)) => quote! { #min..=#max },
+                    (Some(min), None) => quote! { #min.. },
+                    (None, Some(max)) => quote! { ..=#max },
+                    (None, None) => unreachable!("rejected while parsing"),
+                };
+                quote! {
+                    ::yew_bulma::validations::length::ValidatableLength::has_length(&self.#field, #range)
+                }
+            }
+        }
+    }
+}
+
+fn pascal_case(name: &str) -> String {
+    name.split('_')
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+fn snake_case(name: &str) -> String {
+    let mut snake = String::with_capacity(name.len());
+    for (index, c) in name.chars().enumerate() {
+        if c.is_uppercase() {
+            if index > 0 {
+                snake.push('_');
+            }
+            snake.extend(c.to_lowercase());
+        } else {
+            snake.push(c);
+        }
+    }
+    snake
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_generics() {
+        let input: DeriveInput = syn::parse_quote! {
+            struct Wrapper<T> {
+                value: T,
+            }
+        };
+        let error = expand(input).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "BulmaForm can't be derived for generic structs"
+        );
+    }
+}
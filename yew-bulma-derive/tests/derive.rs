@@ -0,0 +1,60 @@
+use yew_bulma::{forms::FormField, validations::ValidationError, BulmaForm};
+
+#[derive(BulmaForm, Debug, Clone, PartialEq)]
+pub struct Profile {
+    #[validate(present, length(max = 8))]
+    pub display_name: String,
+    #[validate(length(min = 2))]
+    pub bio: Option<String>,
+    pub age: Option<u32>,
+}
+
+#[test]
+fn field_ids() {
+    assert_eq!(ProfileField::DisplayName.form_id(), "profile_display_name");
+    assert_eq!(ProfileField::Bio.form_id(), "profile_bio");
+    assert_eq!(ProfileField::Age.form_id(), "profile_age");
+}
+
+#[test]
+fn round_trip() {
+    let profile = Profile {
+        display_name: String::from("jon"),
+        bio: None,
+        age: Some(42),
+    };
+    let storage = ProfileStorage::from(&profile);
+    assert_eq!(storage.to_model().unwrap(), profile);
+
+    let mut storage = ProfileStorage::default();
+    storage.display_name.update(String::from("ecton"));
+    assert_eq!(storage.to_model().unwrap().display_name, "ecton");
+}
+
+#[test]
+fn validations() {
+    let storage = ProfileStorage::default();
+    let errors = storage.validator().validate().expect("name is required");
+    assert_eq!(errors.errors().len(), 1);
+    assert_eq!(
+        errors.errors()[0].primary_field(),
+        ProfileField::DisplayName
+    );
+    assert!(matches!(
+        errors.errors()[0].error,
+        ValidationError::NotPresent
+    ));
+
+    let mut storage = ProfileStorage::default();
+    storage.display_name.update(String::from("a long name"));
+    storage.bio.update(Some(String::from("a")));
+    let errors = storage
+        .validator()
+        .validate()
+        .expect("both fields are invalid");
+    assert_eq!(errors.errors().len(), 2);
+
+    let mut storage = ProfileStorage::default();
+    storage.display_name.update(String::from("jon"));
+    assert!(storage.validator().validate().is_none());
+}